@@ -0,0 +1,51 @@
+//! Day 1 keeping only the three largest sums instead of sorting them all, built as a library the
+//! runner loads from its plugins directory:
+//!
+//! ```
+//! cargo build --release --example s01_select
+//! cp target/release/examples/libs01_select.so plugins/
+//! cargo run --release --bin runner -- compare 1
+//! ```
+
+use anyhow::Result;
+
+use aoc_2022::{export_plugin, InputError, InputIterator, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    /// The three largest sums, largest first.
+    type Input = [usize; 3];
+    type One = usize;
+    type Two = usize;
+
+    const TITLE: Option<&'static str> = Some("Calorie Counting");
+
+    fn parse(it: InputIterator) -> Result<Self::Input> {
+        let mut top = [0; 3];
+        let mut keep = |sum: usize| if let Some(n) = top.iter().position(|&t| sum > t) {
+            top[n..].rotate_right(1);
+            top[n] = sum;
+        };
+        let mut sum = 0;
+        for (n, line) in it.enumerate() {
+            if line.is_empty() {
+                keep(std::mem::take(&mut sum));
+                continue
+            }
+            sum += line.parse::<usize>().map_err(|e| InputError::new(n, &line, e.to_string()))?;
+        }
+        keep(sum);
+        Ok(top)
+    }
+
+    fn part_one(top: &Self::Input) -> Result<Self::One> {
+        Ok(top[0])
+    }
+
+    fn part_two(top: &Self::Input) -> Result<Self::Two> {
+        Ok(top.iter().sum())
+    }
+}
+
+export_plugin!(s01, Day);
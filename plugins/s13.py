@@ -0,0 +1,80 @@
+#! /usr/bin/env python
+
+import json
+import sys
+from functools import cmp_to_key
+
+T = '''[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]
+'''
+
+
+
+def compare(l, r):
+    # print(f'l={l} r={r}')
+    if not l and not r:
+        return 0
+    if not l and r:
+        return 1
+    if l and not r:
+        return -1
+    if isinstance(l[0], int) and isinstance(r[0], int):
+        if l[0] == r[0]:
+            return compare(l[1:], r[1:])
+        return 1 if l[0] < r[0] else -1
+    if isinstance(l[0], list) and isinstance(r[0], list):
+        if (rr := compare(l[0], r[0])) != 0:
+            return rr
+        else:
+            return compare(l[1:], r[1:])
+    rr = compare(l[0], [r[0]]) if isinstance(l[0], list) else compare([l[0]], r[0])
+    return rr if rr else compare(l[1:], r[1:])
+
+
+decoder = json.JSONDecoder()
+
+
+def part_one(text):
+    pairs = [[]]
+    for line in text.splitlines():
+        if not line:
+            pairs.append([])
+            continue
+        pairs[-1].append(decoder.decode(line))
+    return sum([n + 1 for n, p in enumerate(pairs) if compare(p[0], p[1]) > 0])
+
+
+def part_two(text):
+    pairs = [decoder.decode(line) for line in text.splitlines() if line]
+    div1 = [[2]]
+    pairs.append(div1)
+    div2 = [[6]]
+    pairs.append(div2)
+    ll = sorted(pairs, key=cmp_to_key(compare), reverse=True)
+    return (ll.index(div1) + 1) * (ll.index(div2) + 1)
+
+
+if __name__ == '__main__':
+    text = sys.stdin.read()
+    print(part_two(text) if sys.argv[1:] == ['2'] else part_one(text))
@@ -1,128 +1,8 @@
 #! /usr/bin/env python
 
+import sys
 from functools import reduce
 
-I = '''2=--111020=02-=222
-20111==-2001-10
-1211=0020-
-1==2==0-20=1-
-10-=00220--0==1===2
-20
-2==--1---=221
-102=1
-2=1-==-1-211
-2-2100=1000-01-00=
-12=0--
-12=-0=0=11=
-20=1111=22-21-1-=2
-12-00-==1
-21=12222==2-11-
-1-02
-11101=12-
-2002=0--=
-111-20100100=122=2
-10=
-1-===0-12-021==0
-22202212--2-12
-20=1-1
-1=1
-10=-11=0-=12
-111--21=000
-1022-0=1=02=-
-1==2==000=-202--2020
-1==10-==10200001=-
-101=10--01=102
-1-220-2=-000222-
-1=221=2--=1-20
-2--=--0=-=01210-
-1021=21=0=110121=-
-1-11=212=-2-11-
-10=1=0120-0-012-1
-1-0-=11
-11=2==
-10-1--
-1-110--=1-=2--
-21212===010=0-1
-2-2=
-1-10100
-2--0-2---1=002--2=
-12-212=-2=2021
-22211=-=-2
-1-2112
-1=101-111=-1121
-21
-1=10-0=2002-22-2
-1=-2==12=01222-
-112221---=12==0011
-1--1==01
-102-0-
-2-2=0==
-20=
-1=-=-0---00
-1-=00==0--0
-1-----2-
-1=0
-2--2-==0--=2=2=1=22
-20===
-2=-21=012=2
-20-=11=
-1=--102=-
-10-=--=1
-1--1-210
-1011-11-=
-2=-0112-=2-01002--
-111=1-==10=0101--=
-1=-20002--=-22-210
-1---=0-02-100
-2122001012001=02-
-1==--0-0000-
-1=
-22==
-1010=10--100
-2111
-1=2=-
-1-2=2121-1----=-2
-1==11=-201=--=1
-1=2=
-112=1--1-=1
-1-112=2010--1
-2121-0--1010==-
-1=0=0
-112=-00=1-=-=-01
-112=10--20-201=2
-21-210-1
-1==02==120=0=
-1==-0
-2=10-2=2
-11=0=--2-0---
-122=21-=22=2
-111
-1002-102=1-01
-1-1-20-12--
-1=1-=020-
-100=201-1000
-2=-
-1==111-2001
-1==222=122-=0
-1-00=211-2
-12=-0
-1-0=22-02===11-1-
-1--201-120-01
-10
-20=-
-2=2==00--2=121-
-11022120=-=-
-100
-11--2--=12000=1
-201=2==210-
-1200221-=2=-22220
-10220=2-021-1=
-1-=0202=0122-2
-2012-121-02--011
-22=122
-1==-==-120-2-22
-'''
-
 T = '''1=-0-2 
  12111
   2=0=
@@ -211,7 +91,15 @@ def to_rev_list(s):
     return r + ['0'] * (LEN - len(r))
 
 
-i = [to_rev_list(l) for l in I.splitlines()]
-r = reduce(add, i, ['0'] * LEN)
+def part_one(text):
+    i = [to_rev_list(l) for l in text.splitlines()]
+    r = reduce(add, i, ['0'] * LEN)
+    return ''.join(reversed(r)).lstrip('0') or '0'
+
 
-print(''.join(reversed(r)))
+# there is no puzzle in part two, its star comes with the other 49
+if __name__ == '__main__':
+    if sys.argv[1:] == ['2']:
+        print('Merry Christmas')
+    else:
+        print(part_one(sys.stdin.read()))
@@ -0,0 +1,212 @@
+//! The C ABI of days built as shared libraries, so the runner can load a variant of a day without
+//! being rebuilt. A library exports a [Decl] as `AOC_PLUGIN`, see [crate::export_plugin].
+
+use std::cell::{Cell, RefCell};
+use std::ffi::c_void;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+use anyhow::Result;
+
+use crate::{Answer, Solution};
+
+/// Bumped whenever [Decl] or the meaning of its functions change.
+pub const ABI_VERSION: u32 = 1;
+
+/// Name of the exported [Decl].
+pub const SYMBOL: &str = "AOC_PLUGIN";
+
+/// How a call into the library went. With [Status::Answered] the sink got the answer as the JSON
+/// of [Answer], otherwise the message of the error or panic.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Status {
+    Answered,
+    Failed,
+    Panicked,
+}
+
+/// Called by the library with text for the runner, `ctx` is whatever the runner passed along.
+pub type Sink = extern "C" fn(ctx: *mut c_void, text: *const u8, len: usize);
+
+/// Parses `len` bytes of input into `*parsed`, to be released with [Decl::free].
+pub type ParseFn = extern "C" fn(input: *const u8, len: usize, parsed: *mut *mut c_void, sink: Sink, ctx: *mut c_void) -> Status;
+
+/// Solves part `1` or `2` of what [ParseFn] parsed.
+pub type SolveFn = extern "C" fn(parsed: *const c_void, part: u32, sink: Sink, ctx: *mut c_void) -> Status;
+
+pub type FreeFn = extern "C" fn(parsed: *mut c_void);
+
+/// UTF-8 that is not NUL terminated, a null `ptr` stands for none.
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Str {
+    pub ptr: *const u8,
+    pub len: usize,
+}
+
+impl Str {
+    pub const NONE: Str = Str { ptr: std::ptr::null(), len: 0 };
+
+    pub const fn new(s: &'static str) -> Self {
+        Str { ptr: s.as_ptr(), len: s.len() }
+    }
+
+    /// # Safety
+    /// `ptr` has to point to `len` bytes that outlive `'a`.
+    pub unsafe fn to_str<'a>(self) -> Option<&'a str> {
+        if self.ptr.is_null() {
+            return None
+        }
+        std::str::from_utf8(std::slice::from_raw_parts(self.ptr, self.len)).ok()
+    }
+}
+
+/// What a library tells the runner about the day it solves.
+#[repr(C)]
+pub struct Decl {
+    pub abi_version: u32,
+    pub year: u16,
+    /// the `sNN` of the day
+    pub name: Str,
+    pub title: Str,
+    pub implemented: [bool; 2],
+    pub parse: ParseFn,
+    pub solve: SolveFn,
+    pub free: FreeFn,
+}
+
+// SAFETY: the strings point into the library's static data, which nothing writes to.
+unsafe impl Sync for Decl {}
+
+impl Decl {
+    pub const fn new<S: Solution>(year: u16, name: &'static str) -> Self {
+        Decl {
+            abi_version: ABI_VERSION,
+            year,
+            name: Str::new(name),
+            title: match S::TITLE {
+                Some(title) => Str::new(title),
+                None => Str::NONE,
+            },
+            implemented: S::IMPLEMENTED,
+            parse: parse::<S>,
+            solve: solve::<S>,
+            free: free::<S>,
+        }
+    }
+}
+
+thread_local! {
+    static GUARDED: Cell<bool> = const { Cell::new(false) };
+    static LOCATION: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+/// Runs `f` so that neither an error nor a panic leaves the library, they go to the sink instead.
+fn guard<F: FnOnce() -> Result<String>>(f: F, sink: Sink, ctx: *mut c_void) -> Status {
+    // The library has its own copy of std and so its own hook, keep it from printing the panics.
+    static QUIET: Once = Once::new();
+    QUIET.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if GUARDED.with(|g| g.get()) {
+                let location = info.location().map(|l| format!(" at {}:{}", l.file(), l.line())).unwrap_or_default();
+                LOCATION.with(|l| *l.borrow_mut() = location);
+            } else {
+                default_hook(info)
+            }
+        }))
+    });
+    GUARDED.with(|g| g.set(true));
+    let res = panic::catch_unwind(AssertUnwindSafe(f));
+    GUARDED.with(|g| g.set(false));
+    let (status, text) = match res {
+        Ok(Ok(text)) => (Status::Answered, text),
+        Ok(Err(e)) => (Status::Failed, format!("{:#}", e)),
+        Err(payload) => {
+            let msg = payload.downcast_ref::<&str>().map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            (Status::Panicked, msg + &LOCATION.with(|l| l.take()))
+        }
+    };
+    sink(ctx, text.as_ptr(), text.len());
+    status
+}
+
+extern "C" fn parse<S: Solution>(input: *const u8, len: usize, parsed: *mut *mut c_void, sink: Sink, ctx: *mut c_void) -> Status {
+    guard(|| {
+        // SAFETY: the runner hands over `len` bytes of input and a place for the result.
+        let input = unsafe { std::slice::from_raw_parts(input, len) };
+        let input = Box::new(S::parse_bytes(input)?);
+        unsafe { *parsed = Box::into_raw(input) as *mut c_void };
+        Ok(String::new())
+    }, sink, ctx)
+}
+
+extern "C" fn solve<S: Solution>(parsed: *const c_void, part: u32, sink: Sink, ctx: *mut c_void) -> Status {
+    guard(|| {
+        // SAFETY: `parsed` came out of parse::<S>.
+        let input = unsafe { &*(parsed as *const S::Input) };
+        let answer: Answer = match part {
+            1 => S::part_one(input)?.into(),
+            _ => S::part_two(input)?.into(),
+        };
+        Ok(serde_json::to_string(&answer)?)
+    }, sink, ctx)
+}
+
+extern "C" fn free<S: Solution>(parsed: *mut c_void) {
+    // SAFETY: `parsed` came out of parse::<S> and is released only once.
+    drop(unsafe { Box::from_raw(parsed as *mut S::Input) });
+}
+
+/// Exports a [Solution] from a `cdylib` crate for the runner to load, as
+/// `export_plugin!(s01, Day)` or `export_plugin!(2021, s01, Day)`.
+#[macro_export]
+macro_rules! export_plugin {
+    ($name:ident, $day:ty) => {
+        $crate::export_plugin!($crate::DEFAULT_YEAR, $name, $day);
+    };
+    ($year:expr, $name:ident, $day:ty) => {
+        #[no_mangle]
+        pub static AOC_PLUGIN: $crate::abi::Decl = $crate::abi::Decl::new::<$day>($year, stringify!($name));
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ptr;
+
+    use crate::solutions::s01;
+
+    use super::*;
+
+    extern "C" fn collect(ctx: *mut c_void, text: *const u8, len: usize) {
+        let out = unsafe { &mut *(ctx as *mut String) };
+        out.push_str(&String::from_utf8_lossy(unsafe { std::slice::from_raw_parts(text, len) }));
+    }
+
+    #[test]
+    fn round_trip() {
+        let decl = Decl::new::<s01::Day>(2022, "s01");
+        assert_eq!(Some("s01"), unsafe { decl.name.to_str() });
+        let input = s01::Day::EXAMPLES[0].input;
+        let mut parsed = ptr::null_mut();
+        let mut out = String::new();
+        let ctx = &mut out as *mut String as *mut c_void;
+        assert_eq!(Status::Answered, (decl.parse)(input.as_ptr(), input.len(), &mut parsed, collect, ctx));
+        assert_eq!(Status::Answered, (decl.solve)(parsed, 2, collect, ctx));
+        (decl.free)(parsed);
+        assert_eq!(Answer::Integer(45000), serde_json::from_str(&out).unwrap());
+    }
+
+    #[test]
+    fn failure() {
+        let decl = Decl::new::<s01::Day>(2022, "s01");
+        let mut parsed = ptr::null_mut();
+        let mut out = String::new();
+        let status = (decl.parse)(b"x".as_ptr(), 1, &mut parsed, collect, &mut out as *mut String as *mut c_void);
+        assert_eq!((Status::Failed, "line 1: invalid digit found in string: \"x\""), (status, out.as_str()));
+    }
+}
@@ -0,0 +1,123 @@
+use std::fmt::{Display, Formatter};
+
+use num::BigInt;
+use num::bigint::ParseBigIntError;
+use serde::{Deserialize, Serialize, Serializer};
+
+/// What a part of a day produces, kept structured so the runner can render and compare it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case", try_from = "Tagged")]
+pub enum Answer {
+    Integer(i64),
+    /// Serialized as a string, JSON numbers don't survive this size.
+    BigInteger(#[serde(serialize_with = "as_string")] BigInt),
+    Text(String),
+    /// A picture to be read by a human, one string per row.
+    Grid(Vec<String>),
+}
+
+fn as_string<S: Serializer>(n: &BigInt, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(n)
+}
+
+/// [Answer] as it is serialized, read back through [TryFrom] to parse the big integers.
+#[derive(Deserialize)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
+enum Tagged {
+    Integer(i64),
+    BigInteger(String),
+    Text(String),
+    Grid(Vec<String>),
+}
+
+impl TryFrom<Tagged> for Answer {
+    type Error = ParseBigIntError;
+
+    fn try_from(tagged: Tagged) -> Result<Self, Self::Error> {
+        Ok(match tagged {
+            Tagged::Integer(n) => Answer::Integer(n),
+            Tagged::BigInteger(n) => Answer::BigInteger(n.parse()?),
+            Tagged::Text(s) => Answer::Text(s),
+            Tagged::Grid(rows) => Answer::Grid(rows),
+        })
+    }
+}
+
+impl Answer {
+    pub fn grid<I: IntoIterator<Item = S>, S: Into<String>>(rows: I) -> Self {
+        Answer::Grid(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    /// Whether the answer takes more than one line to show.
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Grid(rows) if rows.len() > 1)
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{}", n),
+            Answer::BigInteger(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+macro_rules! integer {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                i64::try_from(n).map_or_else(|_| Answer::BigInteger(n.into()), Answer::Integer)
+            }
+        })*
+    }
+}
+
+integer!(i32, u32, i64, u64, usize, i128, u128);
+
+impl From<BigInt> for Answer {
+    fn from(n: BigInt) -> Self {
+        i64::try_from(&n).map_or(Answer::BigInteger(n), Answer::Integer)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Answer::Integer(42), 42_usize.into());
+        assert_eq!(Answer::BigInteger(BigInt::from(u64::MAX)), u64::MAX.into());
+        assert_eq!(Answer::Integer(-7), BigInt::from(-7).into());
+        assert_eq!(Answer::Text("CMZ".to_string()), "CMZ".into());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!("170141183460469231731687303715884105727", Answer::from(i128::MAX).to_string());
+        assert_eq!("#.\n.#", Answer::grid(["#.", ".#"]).to_string());
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(r#"{"kind":"integer","value":13140}"#, serde_json::to_string(&Answer::Integer(13140)).unwrap());
+        assert_eq!(r#"{"kind":"big_integer","value":"18446744073709551615"}"#, serde_json::to_string(&Answer::from(u64::MAX)).unwrap());
+        assert_eq!(r##"{"kind":"grid","value":["#.",".#"]}"##, serde_json::to_string(&Answer::grid(["#.", ".#"])).unwrap());
+        assert_eq!(Answer::from(u64::MAX), serde_json::from_str(r#"{"kind":"big_integer","value":"18446744073709551615"}"#).unwrap());
+    }
+}
@@ -0,0 +1,118 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Error, Result};
+use serde::Deserialize;
+use toml::Value;
+
+use crate::Part;
+
+#[derive(Debug, Default, Deserialize)]
+struct Expected {
+    one: Option<Value>,
+    two: Option<Value>,
+}
+
+/// Known good answers for the real puzzle inputs, keyed by year, day and part.
+///
+/// The file has one table per day of each year, numbers and text are both accepted:
+///
+/// ```toml
+/// [2022.01]
+/// one = 71506
+/// two = 209603
+///
+/// [2022.05]
+/// one = "JRVNHHCSJ"
+/// ```
+#[derive(Debug, Default)]
+pub struct Answers(HashMap<(u16, usize, Part), String>);
+
+fn to_answer(value: Value) -> String {
+    match value {
+        // pictures are easiest to write as multi-line strings ending in a newline
+        Value::String(s) => s.trim_end_matches('\n').to_string(),
+        v => v.to_string(),
+    }
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| Error::msg(format!("{}: {}", path.display(), e)))?;
+        Answers::parse(&content).map_err(|e| Error::msg(format!("{}: {}", path.display(), e)))
+    }
+
+    pub fn parse(content: &str) -> Result<Self> {
+        let years: BTreeMap<String, BTreeMap<String, Expected>> = toml::from_str(content)?;
+        let mut answers = HashMap::new();
+        for (key, days) in years {
+            let year = key.parse::<u16>().map_err(|_| Error::msg(format!("not a year: {}", key)))?;
+            for (key, expected) in days {
+                let day = key.parse::<usize>().map_err(|_| Error::msg(format!("not a day: {}", key)))?;
+                for (part, value) in [(Part::One, expected.one), (Part::Two, expected.two)] {
+                    if let Some(value) = value {
+                        answers.insert((year, day, part), to_answer(value));
+                    }
+                }
+            }
+        }
+        Ok(Answers(answers))
+    }
+
+    pub fn get(&self, year: u16, day: usize, part: Part) -> Option<&str> {
+        self.0.get(&(year, day, part)).map(|s| s.as_str())
+    }
+}
+
+/// Result of checking one part against the registry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    Missing,
+}
+
+impl Verdict {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Verdict::Pass => "pass",
+            Verdict::Fail => "FAIL",
+            Verdict::Missing => "missing",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static _TEST_DATA: &str = "[2022.01]
+one = 24000
+two = \"45000\"
+
+[2022.5]
+one = \"CMZ\"
+
+[2021.01]
+one = 1711
+";
+
+    #[test]
+    fn parse() {
+        let answers = Answers::parse(_TEST_DATA).unwrap();
+        assert_eq!(Some("24000"), answers.get(2022, 1, Part::One));
+        assert_eq!(Some("45000"), answers.get(2022, 1, Part::Two));
+        assert_eq!(Some("CMZ"), answers.get(2022, 5, Part::One));
+        assert_eq!(None, answers.get(2022, 5, Part::Two));
+        assert_eq!(Some("1711"), answers.get(2021, 1, Part::One));
+        assert_eq!(None, answers.get(2021, 1, Part::Two));
+    }
+
+    #[test]
+    fn bad_day() {
+        assert!(Answers::parse("[2022.one]\none = 1").is_err());
+        assert!(Answers::parse("[last.01]\none = 1").is_err());
+    }
+}
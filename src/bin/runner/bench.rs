@@ -0,0 +1,130 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::{Error, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::Part;
+
+/// Timing statistics over a number of runs, in nanoseconds so they survive a round trip through TOML.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub runs: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub stddev_ns: u64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut ns = samples.iter().map(|d| d.as_nanos() as u64).collect::<Vec<_>>();
+        ns.sort_unstable();
+        let runs = ns.len();
+        if runs == 0 {
+            return Stats::default()
+        }
+        let median_ns = if runs % 2 == 0 {
+            (ns[runs / 2 - 1] + ns[runs / 2]) / 2
+        } else {
+            ns[runs / 2]
+        };
+        let mean = ns.iter().map(|&n| n as f64).sum::<f64>() / runs as f64;
+        let variance = ns.iter().map(|&n| (n as f64 - mean).powi(2)).sum::<f64>() / runs as f64;
+        Stats { runs, min_ns: ns[0], median_ns, mean_ns: mean as u64, stddev_ns: variance.sqrt() as u64 }
+    }
+
+    /// Whether the median got slower than `baseline` by more than `threshold` percent.
+    pub fn regressed(&self, baseline: &Stats, threshold: f64) -> bool {
+        self.median_ns as f64 > baseline.median_ns as f64 * (1.0 + threshold / 100.0)
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let d = Duration::from_nanos;
+        write!(f, "runs {}  min {:.3?}  median {:.3?}  mean {:.3?}  stddev {:.3?}",
+               self.runs, d(self.min_ns), d(self.median_ns), d(self.mean_ns), d(self.stddev_ns))
+    }
+}
+
+fn part_key(part: Part) -> &'static str {
+    match part {
+        Part::One => "one",
+        Part::Two => "two",
+        Part::Both => "both",
+    }
+}
+
+/// Saved benchmark results, one table per day of each year with an entry per part.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Baseline(BTreeMap<String, BTreeMap<String, BTreeMap<String, Stats>>>);
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| Error::msg(format!("{}: {}", path.display(), e)))?;
+        toml::from_str(&content).map_err(|e| Error::msg(format!("{}: {}", path.display(), e)))
+    }
+
+    /// Like [Baseline::load], but a file that does not exist yet is just an empty baseline.
+    pub fn load_or_default(path: &Path) -> Result<Self> {
+        if path.exists() {
+            Baseline::load(path)
+        } else {
+            Ok(Baseline::default())
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, toml::to_string(self)?)
+            .map_err(|e| Error::msg(format!("{}: {}", path.display(), e)))
+    }
+
+    pub fn get(&self, year: u16, day: usize, part: Part) -> Option<&Stats> {
+        self.0.get(&year.to_string())
+            .and_then(|days| days.get(&format!("{:02}", day)))
+            .and_then(|parts| parts.get(part_key(part)))
+    }
+
+    pub fn insert(&mut self, year: u16, day: usize, part: Part, stats: Stats) {
+        self.0.entry(year.to_string()).or_default()
+            .entry(format!("{:02}", day)).or_default()
+            .insert(part_key(part).to_string(), stats);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let samples = [5, 1, 3, 2, 4].map(Duration::from_nanos);
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(Stats { runs: 5, min_ns: 1, median_ns: 3, mean_ns: 3, stddev_ns: 1 }, stats);
+        assert_eq!(2, Stats::from_samples(&samples[1..]).median_ns);
+    }
+
+    #[test]
+    fn regression() {
+        let baseline = Stats { median_ns: 100, ..Stats::default() };
+        assert!(!Stats { median_ns: 105, ..baseline }.regressed(&baseline, 10.0));
+        assert!(Stats { median_ns: 111, ..baseline }.regressed(&baseline, 10.0));
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut baseline = Baseline::default();
+        baseline.insert(2022, 5, Part::Two, Stats { runs: 3, min_ns: 1, median_ns: 2, mean_ns: 2, stddev_ns: 0 });
+        let saved = toml::to_string(&baseline).unwrap();
+        let loaded: Baseline = toml::from_str(&saved).unwrap();
+        assert_eq!(baseline.get(2022, 5, Part::Two), loaded.get(2022, 5, Part::Two));
+        assert_eq!(None, loaded.get(2022, 5, Part::One));
+        assert_eq!(None, loaded.get(2021, 5, Part::Two));
+    }
+}
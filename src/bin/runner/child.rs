@@ -0,0 +1,177 @@
+use std::ffi::OsString;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::process::{Command, ExitStatus, Stdio};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::logger::emit;
+use crate::outcome::Outcome;
+use crate::solver::Solver;
+use crate::{run_each, Part, Steps, Timing};
+
+/// What a solution may use. With no limit at all a Rust day runs inside the runner,
+/// otherwise in a child process that is killed when it runs out of time.
+#[derive(Debug, Default, Clone)]
+pub struct Limits {
+    pub timeout: Option<Duration>,
+    /// address space of the child in bytes
+    pub memory: Option<u64>,
+    /// the runner's flags the child needs too, so that it logs the same way and looks in the
+    /// same directories
+    pub args: Vec<OsString>,
+}
+
+impl Limits {
+    pub fn any(&self) -> bool {
+        self.timeout.is_some() || self.memory.is_some()
+    }
+}
+
+/// One part as the child reports it on its stdout, a line of JSON each.
+#[derive(Debug, Serialize, Deserialize)]
+struct Reported {
+    part: Part,
+    outcome: Outcome,
+    parse_ns: u64,
+    solve_ns: u64,
+}
+
+/// The child's side: solves the input read from stdin and reports each part as soon as it is done.
+pub fn serve<S: Steps>(steps: &S, part: Part) -> Result<()> {
+    let mut input = Vec::new();
+    io::stdin().read_to_end(&mut input)?;
+    run_each(steps, &input, part, |part, outcome, timing| {
+        let reported = Reported { part, outcome, parse_ns: timing.parse.as_nanos() as u64, solve_ns: timing.solve.as_nanos() as u64 };
+        println!("{}", serde_json::to_string(&reported).unwrap_or_default());
+    });
+    Ok(())
+}
+
+/// Like [crate::run], in a child process held to `limits`. Parts the child didn't report get
+/// the reason it stopped.
+pub fn run(solver: &Solver, input: &[u8], part: Part, limits: &Limits) -> Vec<(Part, Outcome, Timing)> {
+    let start = Instant::now();
+    let (reported, stopped) = match supervise(solver, input, part, limits) {
+        Ok(res) => res,
+        Err(e) => (vec![], Outcome::Failed(format!("cannot run day {:02} in a child process: {}", solver.day(), e))),
+    };
+    let parse = Duration::from_nanos(reported.first().map_or(0, |r| r.parse_ns));
+    part.parts().iter().map(|&p| match reported.iter().find(|r| r.part == p) {
+        Some(r) => (p, r.outcome.clone(), Timing { parse, solve: Duration::from_nanos(r.solve_ns) }),
+        None => (p, stopped.clone(), Timing { parse, solve: start.elapsed().saturating_sub(parse) }),
+    }).collect()
+}
+
+fn supervise(solver: &Solver, input: &[u8], part: Part, limits: &Limits) -> Result<(Vec<Reported>, Outcome)> {
+    let part_arg = part.to_possible_value().map(|v| v.get_name().to_string()).unwrap_or_default();
+    let mut command = Command::new(std::env::current_exe()?);
+    command.args(["child", &solver.day().to_string(), &part_arg, "--year", &solver.year().to_string()])
+        .args(&limits.args);
+    if let Solver::Library(library) = solver {
+        command.arg("--library").arg(&library.path);
+    }
+    let mut reported = vec![];
+    let ended = execute(&mut command, input, limits, |line| match serde_json::from_str::<Reported>(&line) {
+        Ok(r) => reported.push(r),
+        Err(_) => emit(line),
+    })?;
+    let stopped = ended.limit.unwrap_or_else(|| Outcome::Failed(format!("child process stopped: {}", ended.status)));
+    Ok((reported, stopped))
+}
+
+/// How a process run by [execute] ended.
+pub struct Ended {
+    pub status: ExitStatus,
+    /// [Outcome::TimedOut] or [Outcome::OutOfMemory] when it ran into one of the limits
+    pub limit: Option<Outcome>,
+    /// what it wrote to stderr, already passed on to the log
+    pub errors: Vec<String>,
+}
+
+/// Runs `command` held to `limits` with `input` on its stdin, handing `on_line` every line of its
+/// stdout as it comes. A process still running at the timeout is killed.
+pub fn execute<F: FnMut(String)>(command: &mut Command, input: &[u8], limits: &Limits, mut on_line: F) -> Result<Ended> {
+    command.stdin(Stdio::piped()).stdout(Stdio::piped()).stderr(Stdio::piped());
+    if let Some(memory) = limits.memory {
+        limit_memory(command, memory)?;
+    }
+    let mut child = command.spawn()?;
+
+    let mut stdin = child.stdin.take();
+    let input = input.to_vec();
+    let writer = thread::spawn(move || stdin.as_mut().map(|s| s.write_all(&input)));
+    let stderr = child.stderr.take().map(|err| thread::spawn(move || BufReader::new(err).lines().map_while(Result::ok).collect::<Vec<_>>()));
+    let (sender, receiver) = mpsc::channel();
+    if let Some(out) = child.stdout.take() {
+        thread::spawn(move || BufReader::new(out).lines().map_while(Result::ok).for_each(|line| {
+            let _ = sender.send(line);
+        }));
+    }
+
+    let deadline = limits.timeout.map(|t| Instant::now() + t);
+    let mut timed_out = false;
+    loop {
+        let line = match deadline {
+            Some(deadline) => receiver.recv_timeout(deadline.saturating_duration_since(Instant::now())),
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        match line {
+            Ok(line) => on_line(line),
+            Err(RecvTimeoutError::Timeout) => {
+                timed_out = true;
+                let _ = child.kill();
+                break
+            }
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+    let status = child.wait()?;
+    let _ = writer.join();
+    let errors = stderr.and_then(|t| t.join().ok()).unwrap_or_default();
+    let oom = errors.iter().any(|l| l.starts_with("memory allocation of") || l.starts_with("MemoryError"));
+    errors.iter().cloned().for_each(emit);
+    let limit = match (timed_out, limits.timeout, limits.memory) {
+        (true, Some(timeout), _) => Some(Outcome::TimedOut(timeout)),
+        (_, _, Some(memory)) if oom => Some(Outcome::OutOfMemory(memory)),
+        _ => None,
+    };
+    Ok(Ended { status, limit, errors })
+}
+
+#[cfg(unix)]
+fn limit_memory(command: &mut Command, bytes: u64) -> Result<()> {
+    use std::os::unix::process::CommandExt;
+    let limit = libc::rlimit { rlim_cur: bytes as libc::rlim_t, rlim_max: bytes as libc::rlim_t };
+    // SAFETY: setrlimit is async-signal-safe and only touches the child about to exec.
+    unsafe {
+        command.pre_exec(move || {
+            if libc::setrlimit(libc::RLIMIT_AS, &limit) == 0 { Ok(()) } else { Err(io::Error::last_os_error()) }
+        });
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn limit_memory(_: &mut Command, _: u64) -> Result<()> {
+    Err(anyhow::Error::msg("--memory needs setrlimit, which this platform doesn't have"))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_2022::Answer;
+
+    use super::*;
+
+    #[test]
+    fn reported_line() {
+        let reported = Reported { part: Part::Two, outcome: Outcome::Answered(Answer::from(u64::MAX)), parse_ns: 5, solve_ns: 7 };
+        let line = serde_json::to_string(&reported).unwrap();
+        let read = serde_json::from_str::<Reported>(&line).unwrap();
+        assert_eq!((Part::Two, reported.outcome, 7), (read.part, read.outcome, read.solve_ns));
+    }
+}
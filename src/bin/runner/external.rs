@@ -0,0 +1,128 @@
+use std::env::consts::DLL_EXTENSION;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use num::BigInt;
+
+use aoc_2022::{Answer, DEFAULT_YEAR};
+
+use crate::child::{execute, Limits};
+use crate::outcome::Outcome;
+use crate::{Part, Timing};
+
+/// A day solved by an executable written in whatever language: it gets the input on stdin and
+/// the part, `1` or `2`, as its only argument, and prints the answer on stdout. Printing nothing
+/// means the part isn't solved yet, exiting with an error fails it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct External {
+    pub year: u16,
+    pub day: usize,
+    pub path: PathBuf,
+}
+
+impl External {
+    /// The `sNN` executables, with any extension but a shared library's, in `dir` for the default year and in its `YYYY`
+    /// subdirectories for the others. A missing directory has none.
+    pub fn discover(dir: &Path) -> Result<Vec<External>> {
+        let mut found = vec![];
+        if !dir.is_dir() {
+            return Ok(found)
+        }
+        for entry in fs::read_dir(dir).with_context(|| dir.display().to_string())? {
+            let path = entry?.path();
+            let year = path.file_name().and_then(|n| n.to_str()).and_then(|n| n.parse::<u16>().ok());
+            match year {
+                Some(year) if path.is_dir() => for entry in fs::read_dir(&path).with_context(|| path.display().to_string())? {
+                    found.extend(External::from_path(year, entry?.path()));
+                },
+                _ => found.extend(External::from_path(DEFAULT_YEAR, path)),
+            }
+        }
+        found.sort_by_key(|e| (e.year, e.day));
+        Ok(found)
+    }
+
+    fn from_path(year: u16, path: PathBuf) -> Option<External> {
+        if path.extension().is_some_and(|e| e == DLL_EXTENSION) {
+            return None
+        }
+        let stem = path.file_stem()?.to_str()?;
+        let day = stem.strip_prefix('s').filter(|d| d.len() == 2)?.parse().ok()?;
+        is_executable(&path).then_some(External { year, day, path })
+    }
+
+    /// Runs the executable once per part, there is no parse shared between them to time.
+    pub fn run(&self, input: &[u8], part: Part, limits: &Limits) -> Vec<(Part, Outcome, Timing)> {
+        part.parts().iter().map(|&p| {
+            let start = Instant::now();
+            let outcome = self.run_part(input, p, limits).unwrap_or_else(|e| Outcome::Failed(format!("{}: {:#}", self.path.display(), e)));
+            (p, outcome, Timing { parse: Duration::ZERO, solve: start.elapsed() })
+        }).collect()
+    }
+
+    fn run_part(&self, input: &[u8], part: Part, limits: &Limits) -> Result<Outcome> {
+        let mut lines = vec![];
+        let ended = execute(Command::new(&self.path).arg((part as usize).to_string()), input, limits, |line| lines.push(line))?;
+        Ok(match ended.limit {
+            Some(limit) => limit,
+            None if !ended.status.success() => Outcome::Failed(match ended.errors.last() {
+                Some(error) => format!("day {:02} part {}: {}", self.day, part, error),
+                None => format!("day {:02} part {}: {}", self.day, part, ended.status),
+            }),
+            None => read_answer(&lines).map_or(Outcome::Unimplemented, Outcome::Answered),
+        })
+    }
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    path.metadata().is_ok_and(|m| m.is_file() && m.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
+
+/// Numbers become integers and several lines a picture, anything else is text.
+fn read_answer(lines: &[String]) -> Option<Answer> {
+    let lines = lines.iter().map(|l| l.trim_end()).collect::<Vec<_>>();
+    let end = lines.iter().rposition(|l| !l.is_empty())? + 1;
+    Some(match &lines[..end] {
+        [line] => line.parse::<BigInt>().map_or_else(|_| Answer::from(*line), Answer::from),
+        rows => Answer::grid(rows.iter().copied()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers() {
+        let lines = |ls: &[&str]| ls.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+        assert_eq!(None, read_answer(&lines(&[])));
+        assert_eq!(Some(Answer::Integer(140)), read_answer(&lines(&["140", ""])));
+        assert_eq!(Some(Answer::from("2=-1=0")), read_answer(&lines(&["2=-1=0"])));
+        assert_eq!(Some(Answer::grid(["#.", ".#"])), read_answer(&lines(&["#.", ".#"])));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn discover() {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = std::env::temp_dir().join(format!("aoc-external-{}", std::process::id()));
+        fs::create_dir_all(dir.join("2021")).unwrap();
+        for (name, mode) in [("s13.py", 0o755), ("2021/s01.sh", 0o755), ("s25.txt", 0o644), ("README.md", 0o755)] {
+            fs::write(dir.join(name), "").unwrap();
+            fs::set_permissions(dir.join(name), fs::Permissions::from_mode(mode)).unwrap();
+        }
+        let found = External::discover(&dir).unwrap().into_iter().map(|e| (e.year, e.day)).collect::<Vec<_>>();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(vec![(2021, 1), (2022, 13)], found);
+    }
+}
@@ -0,0 +1,95 @@
+use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, ErrorKind};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Error, Result};
+
+use aoc_2022::DEFAULT_YEAR;
+
+/// Where the puzzle input of a run comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// An explicit `--input`, `-` stands for stdin.
+    pub fn from_arg(path: &Path) -> Self {
+        if path == Path::new("-") {
+            Source::Stdin
+        } else {
+            Source::File(path.to_path_buf())
+        }
+    }
+
+    /// The conventional `YYYY/NN.txt` of a day inside the inputs directory. Inputs of the
+    /// default year may sit in the inputs directory itself, as they did before there were years.
+    pub fn for_day(dir: &Path, year: u16, day: usize) -> Self {
+        let file = format!("{:02}.txt", day);
+        let year_dir = dir.join(year.to_string());
+        if year == DEFAULT_YEAR && !year_dir.is_dir() {
+            Source::File(dir.join(file))
+        } else {
+            Source::File(year_dir.join(file))
+        }
+    }
+
+    /// Picks the explicit input if there is one, the day's file otherwise.
+    pub fn choose(input: Option<&Path>, dir: &Path, year: u16, day: usize) -> Self {
+        input.map_or_else(|| Source::for_day(dir, year, day), Source::from_arg)
+    }
+
+    fn open(&self) -> Result<Box<dyn BufRead>> {
+        Ok(match self {
+            Source::File(path) => Box::new(BufReader::new(File::open(path)
+                .map_err(|e| Error::msg(format!("{}: {}", self, e)))?)),
+            Source::Stdin => Box::new(BufReader::new(io::stdin())),
+        })
+    }
+
+    /// Reads the whole input, a failure part way through names the line it happened on.
+    pub fn read(&self) -> Result<Vec<u8>> {
+        let mut reader = self.open()?;
+        let mut content = Vec::new();
+        let mut line = 1;
+        loop {
+            match reader.read_until(b'\n', &mut content) {
+                Ok(0) => return Ok(content),
+                Ok(_) => line += 1,
+                Err(e) if e.kind() == ErrorKind::Interrupted => (),
+                Err(e) => return Err(Error::msg(format!("{}: line {}: {}", self, line, e))),
+            }
+        }
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::File(path) => write!(f, "{}", path.display()),
+            Source::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn choose() {
+        let dir = Path::new("inputs");
+        assert_eq!(Source::File(PathBuf::from("inputs/07.txt")), Source::choose(None, dir, 2022, 7));
+        assert_eq!(Source::File(PathBuf::from("inputs/2021/07.txt")), Source::choose(None, dir, 2021, 7));
+        assert_eq!(Source::Stdin, Source::choose(Some(Path::new("-")), dir, 2022, 7));
+        assert_eq!(Source::File(PathBuf::from("x.txt")), Source::choose(Some(Path::new("x.txt")), dir, 2022, 7));
+    }
+
+    #[test]
+    fn missing_file_is_named() {
+        let err = Source::for_day(Path::new("no/such/dir"), 2022, 3).read().err().unwrap();
+        assert!(err.to_string().starts_with("no/such/dir/03.txt: "));
+    }
+}
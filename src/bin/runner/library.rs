@@ -0,0 +1,127 @@
+use std::env::consts::DLL_EXTENSION;
+use std::ffi::c_void;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::ptr;
+
+use anyhow::{Context, Error, Result};
+use log::warn;
+
+use aoc_2022::abi::{Decl, FreeFn, ParseFn, SolveFn, Status, ABI_VERSION, SYMBOL};
+use aoc_2022::Answer;
+
+use crate::outcome::{panicked, Outcome};
+use crate::{Part, Steps};
+
+/// A day loaded from a shared library in the plugins directory, built with `export_plugin!`.
+pub struct Library {
+    pub year: u16,
+    pub day: usize,
+    pub title: Option<String>,
+    pub implemented: [bool; 2],
+    pub path: PathBuf,
+    parse: ParseFn,
+    solve: SolveFn,
+    free: FreeFn,
+    /// keeps the functions above loaded
+    _library: libloading::Library,
+}
+
+impl Library {
+    /// Every shared library directly in `dir`, the year and day come from the library itself. One
+    /// that can't be loaded, built for another ABI version say, is left out with a warning.
+    pub fn discover(dir: &Path) -> Result<Vec<Library>> {
+        let mut found = vec![];
+        if !dir.is_dir() {
+            return Ok(found)
+        }
+        for entry in fs::read_dir(dir).with_context(|| dir.display().to_string())? {
+            let path = entry?.path();
+            if path.is_file() && path.extension().is_some_and(|e| e == DLL_EXTENSION) {
+                match Library::load(&path) {
+                    Ok(library) => found.push(library),
+                    Err(e) => warn!("skipping {}: {}", path.display(), e),
+                }
+            }
+        }
+        found.sort_by(|a, b| (a.year, a.day, &a.path).cmp(&(b.year, b.day, &b.path)));
+        Ok(found)
+    }
+
+    pub fn load(path: &Path) -> Result<Library> {
+        // SAFETY: loading runs the library's initializers, whatever is in the plugins directory is trusted.
+        let library = unsafe { libloading::Library::new(path)? };
+        let decl = unsafe { library.get::<*const Decl>(SYMBOL.as_bytes()) }
+            .map_err(|_| Error::msg(format!("not a plugin, there is no {}", SYMBOL)))?;
+        // SAFETY: the symbol is a static Decl that lives as long as the library.
+        let decl = unsafe { &**decl };
+        if decl.abi_version != ABI_VERSION {
+            return Err(Error::msg(format!("built for ABI version {}, this runner has {}", decl.abi_version, ABI_VERSION)))
+        }
+        let name = unsafe { decl.name.to_str() }.context("name is not UTF-8")?;
+        let day = name.strip_prefix('s').and_then(|d| d.parse().ok())
+            .ok_or_else(|| Error::msg(format!("{:?} is not the name of a day", name)))?;
+        Ok(Library {
+            year: decl.year,
+            day,
+            title: unsafe { decl.title.to_str() }.map(|t| t.to_string()),
+            implemented: decl.implemented,
+            path: path.to_path_buf(),
+            parse: decl.parse,
+            solve: decl.solve,
+            free: decl.free,
+            _library: library,
+        })
+    }
+}
+
+extern "C" fn collect(ctx: *mut c_void, text: *const u8, len: usize) {
+    // SAFETY: ctx is the String handed to the library by [call], text the library's `len` bytes.
+    let out = unsafe { &mut *(ctx as *mut String) };
+    out.push_str(&String::from_utf8_lossy(unsafe { std::slice::from_raw_parts(text, len) }));
+}
+
+/// Calls into the library with a sink for its text, errors and panics become their [Outcome].
+fn call<F: FnOnce(*mut c_void) -> Status>(f: F) -> Result<String, Outcome> {
+    let mut text = String::new();
+    match f(&mut text as *mut String as *mut c_void) {
+        Status::Answered => Ok(text),
+        Status::Failed => Err(Outcome::Failed(text)),
+        Status::Panicked => Err(panicked(text)),
+    }
+}
+
+/// Input parsed by a library, released by it again.
+pub struct Parsed {
+    ptr: *mut c_void,
+    free: FreeFn,
+}
+
+impl Drop for Parsed {
+    fn drop(&mut self) {
+        (self.free)(self.ptr)
+    }
+}
+
+impl Steps for Library {
+    type Parsed = Parsed;
+
+    fn parse_input(&self, input: &[u8]) -> Result<Parsed, Outcome> {
+        let mut ptr = ptr::null_mut();
+        call(|ctx| (self.parse)(input.as_ptr(), input.len(), &mut ptr, collect, ctx))
+            .map_err(|o| match o {
+                Outcome::Failed(msg) => Outcome::Failed(format!("day {:02}: bad input: {}", self.day, msg)),
+                o => o,
+            })?;
+        Ok(Parsed { ptr, free: self.free })
+    }
+
+    fn solve_part(&self, parsed: &Parsed, part: Part) -> Outcome {
+        call(|ctx| (self.solve)(parsed.ptr, part as u32, collect, ctx))
+            .and_then(|json| serde_json::from_str::<Answer>(&json).map_err(|e| Outcome::Failed(format!("bad answer {:?}: {}", json, e))))
+            .map_or_else(|o| match o {
+                Outcome::Failed(msg) => Outcome::Failed(format!("day {:02} part {}: {}", self.day, part, msg)),
+                o => o,
+            }, Outcome::Answered)
+    }
+}
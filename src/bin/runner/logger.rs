@@ -0,0 +1,106 @@
+use std::cell::RefCell;
+
+use log::{LevelFilter, Log, Metadata, Record, SetLoggerError};
+
+const CRATE: &str = "aoc_2022";
+const RUNNER: &str = env!("CARGO_CRATE_NAME");
+const SOLUTIONS: &str = "aoc_2022::solutions::";
+
+thread_local! {
+    static CAPTURED: RefCell<Option<Vec<String>>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` keeping the lines it logs on this thread instead of writing them, so that days
+/// running side by side don't mix their logs.
+pub fn capture<T, F: FnOnce() -> T>(f: F) -> (T, Vec<String>) {
+    CAPTURED.with(|c| *c.borrow_mut() = Some(vec![]));
+    let res = f();
+    (res, CAPTURED.with(|c| c.borrow_mut().take()).unwrap_or_default())
+}
+
+/// Writes a line of log, or keeps it when this thread is capturing.
+pub fn emit(line: String) {
+    CAPTURED.with(|c| match c.borrow_mut().as_mut() {
+        Some(lines) => lines.push(line),
+        None => eprintln!("{}", line),
+    });
+}
+
+/// Writes the log records of the solutions and the runner to stderr, optionally only those of some days.
+pub struct Logger {
+    level: LevelFilter,
+    /// one level quieter for the crates we use, day 12's graph library warns about every loop
+    deps: LevelFilter,
+    days: Vec<usize>,
+}
+
+impl Logger {
+    /// Warnings by default, every `-v` shows one more level and every `-q` one less.
+    pub fn new(verbose: u8, quiet: u8, days: Vec<usize>) -> Self {
+        let levels = LevelFilter::iter().collect::<Vec<_>>();
+        let index = (LevelFilter::Warn as usize + verbose as usize).saturating_sub(quiet as usize);
+        let level = index.min(levels.len() - 1);
+        Logger { level: levels[level], deps: levels[level.saturating_sub(1)], days }
+    }
+
+    pub fn install(self) -> Result<(), SetLoggerError> {
+        log::set_max_level(self.level);
+        log::set_boxed_logger(Box::new(self))
+    }
+
+    /// The day a record comes from, `None` for everything outside of the solutions.
+    fn day(target: &str) -> Option<usize> {
+        target.strip_prefix(SOLUTIONS)?.rsplit("::").next()?.strip_prefix('s')?.parse().ok()
+    }
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        let ours = [CRATE, RUNNER].contains(&metadata.target().split("::").next().unwrap_or_default());
+        let level = if ours { self.level } else { self.deps };
+        metadata.level() <= level
+            && (self.days.is_empty() || Logger::day(metadata.target()).is_none_or(|day| self.days.contains(&day)))
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            let target = record.target().rsplit("::").next().unwrap_or_default();
+            emit(format!("[{:<5} {}] {}", record.level(), target, record.args()));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use log::Level;
+
+    use super::*;
+
+    fn enabled(logger: &Logger, level: Level, target: &str) -> bool {
+        logger.enabled(&Metadata::builder().level(level).target(target).build())
+    }
+
+    #[test]
+    fn levels() {
+        assert_eq!(LevelFilter::Warn, Logger::new(0, 0, vec![]).level);
+        assert_eq!(LevelFilter::Trace, Logger::new(5, 0, vec![]).level);
+        assert_eq!(LevelFilter::Error, Logger::new(0, 1, vec![]).level);
+        assert_eq!(LevelFilter::Off, Logger::new(1, 4, vec![]).level);
+    }
+
+    #[test]
+    fn days() {
+        let logger = Logger::new(2, 0, vec![17]);
+        assert!(enabled(&logger, Level::Debug, "aoc_2022::solutions::s17"));
+        assert!(!enabled(&logger, Level::Trace, "aoc_2022::solutions::s17"));
+        assert!(!enabled(&logger, Level::Debug, "aoc_2022::solutions::s20"));
+        assert!(!enabled(&logger, Level::Debug, "aoc_2022::solutions::y2021::s20"));
+        assert!(enabled(&logger, Level::Debug, "aoc_2022::solutions::y2021::s17"));
+        assert!(enabled(&logger, Level::Debug, "aoc_2022"));
+        assert!(enabled(&logger, Level::Debug, "runner::library"));
+        assert!(enabled(&logger, Level::Info, "input_graph"));
+        assert!(!enabled(&logger, Level::Debug, "input_graph"));
+    }
+}
@@ -0,0 +1,568 @@
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::{iter, thread};
+use std::time::{Duration, Instant};
+
+use anyhow::{Error, Result};
+use clap::error::ErrorKind;
+use clap::{ArgAction, CommandFactory, Parser, Subcommand, ValueEnum};
+use serde::{Deserialize, Serialize};
+
+use aoc_2022::{Answer, DEFAULT_YEAR, Parsed, Plugin};
+
+use crate::answers::{Answers, Verdict};
+use crate::bench::{Baseline, Stats};
+use crate::child::Limits;
+use crate::input::Source;
+use crate::library::Library;
+use crate::logger::{capture, Logger};
+use crate::outcome::{install_panic_hook, isolate_result, Outcome};
+use crate::report::{Format, Record, Report};
+use crate::scaffold::Scaffold;
+use crate::solver::{solvers, variants, Plugins, Solver};
+
+mod answers;
+mod bench;
+mod child;
+mod external;
+mod input;
+mod library;
+mod logger;
+mod outcome;
+mod pool;
+mod report;
+mod scaffold;
+mod solver;
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Part {
+    #[default]
+    One = 1,
+    Two,
+    /// both parts from a single parse of the input
+    Both,
+}
+
+impl Part {
+    /// The single parts to compute for this choice.
+    fn parts(self) -> &'static [Part] {
+        match self {
+            Part::One => &[Part::One],
+            Part::Two => &[Part::Two],
+            Part::Both => &[Part::One, Part::Two],
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::Both => f.pad("both"),
+            part => f.pad(&(*part as usize).to_string()),
+        }
+    }
+}
+
+#[derive(Parser, Debug)]
+#[command(bin_name = "runner")]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// day of the contest
+    day: Option<usize>,
+    #[arg(value_enum)]
+    part: Option<Part>,
+    /// read the input from this file instead of the day's file, - for stdin
+    #[arg(long, value_name = "PATH")]
+    input: Option<PathBuf>,
+    /// run the day's examples instead of its input and check their known answers
+    #[arg(long, conflicts_with_all = ["input", "format"])]
+    example: bool,
+    /// event the days belong to
+    #[arg(long, env = "AOC_YEAR", default_value_t = DEFAULT_YEAR, global = true)]
+    year: u16,
+    /// directory holding shared libraries with variants of days and executables named sNN or YYYY/sNN
+    #[arg(long, env = "AOC_PLUGINS_DIR", default_value = "plugins", global = true)]
+    plugins_dir: PathBuf,
+    /// directory holding the YYYY/NN.txt inputs
+    #[arg(long, env = "AOC_INPUTS_DIR", default_value = ".", global = true)]
+    inputs_dir: PathBuf,
+    /// how answers are written to stdout, by a single day and all
+    #[arg(long, value_enum, default_value_t = Format::Plain, global = true)]
+    format: Format,
+    /// show more of the solutions' log, repeat for debug and trace
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+    /// show less of the solutions' log, errors only or nothing at all
+    #[arg(short, long, action = ArgAction::Count, global = true, conflicts_with = "verbose")]
+    quiet: u8,
+    /// only log from this day, can be repeated
+    #[arg(long, value_name = "DAY", global = true)]
+    log_day: Vec<usize>,
+    /// stop a day that runs longer than this many seconds, it then runs in a child process
+    #[arg(long, value_name = "SECS", global = true)]
+    timeout: Option<f64>,
+    /// limit the address space of a day to this many MiB, it then runs in a child process
+    #[arg(long, value_name = "MIB", global = true)]
+    memory: Option<u64>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// run both parts of every day and print a table of the results, an executable next to a Rust
+    /// day only runs under compare
+    All {
+        /// number of days running at the same time, all cores by default
+        #[arg(short, long)]
+        jobs: Option<usize>,
+    },
+    /// show the registered solutions, which parts they solve and what they have to run on
+    List,
+    /// check every day against the known answers, running the same solver as all
+    Verify {
+        /// file with the expected answers
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+    },
+    /// run every solver there is for a day on the same input and check that they agree
+    Compare {
+        /// day of the contest
+        day: usize,
+        #[arg(value_enum)]
+        part: Option<Part>,
+        /// read the input from this file instead of the day's file, - for stdin
+        #[arg(long, value_name = "PATH")]
+        input: Option<PathBuf>,
+    },
+    /// time repeated runs of one solution
+    Bench {
+        /// day of the contest
+        day: usize,
+        #[arg(value_enum)]
+        part: Option<Part>,
+        /// read the input from this file instead of the day's file, - for stdin
+        #[arg(long, value_name = "PATH")]
+        input: Option<PathBuf>,
+        /// number of measured runs
+        #[arg(short = 'n', long, default_value_t = 10)]
+        runs: usize,
+        /// number of unmeasured runs before measuring
+        #[arg(long, default_value_t = 2)]
+        warmup: usize,
+        /// store the results in this file, keeping the other entries
+        #[arg(long)]
+        save: Option<PathBuf>,
+        /// compare the results against the ones stored in this file
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// allowed slowdown of the median against the baseline, in percent
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// solve the input on stdin and report the parts as JSON lines, how days run under limits
+    #[command(hide = true)]
+    Child {
+        day: usize,
+        #[arg(value_enum)]
+        part: Part,
+        /// the shared library to solve with instead of the compiled in day
+        #[arg(long)]
+        library: Option<PathBuf>,
+    },
+    /// add a day from the s00 template, with an empty input file in the inputs directory
+    New {
+        /// day of the contest
+        day: usize,
+    },
+}
+
+impl Args {
+    /// Parses the command line with the checks clap can't make itself: the day to run, its part and
+    /// where its input comes from don't go with a command, and only a day and `all` have their
+    /// results written in another `--format`.
+    fn parse_from<I: IntoIterator<Item = T>, T: Into<std::ffi::OsString> + Clone>(args: I) -> Result<Args, clap::Error> {
+        let args = Args::try_parse_from(args)?;
+        if args.command.is_some() && (args.day.is_some() || args.part.is_some() || args.input.is_some() || args.example) {
+            return Err(Args::command().error(ErrorKind::ArgumentConflict, "a day, part, --input or --example can't go with a command"))
+        }
+        if args.format != Format::Plain && args.command.as_ref().is_some_and(|c| !matches!(c, Command::All { .. })) {
+            return Err(Args::command().error(ErrorKind::ArgumentConflict, "--format only applies to a single day and all"))
+        }
+        Ok(args)
+    }
+}
+
+/// Time spent in each step of a run.
+#[derive(Debug, Default, Copy, Clone)]
+struct Timing {
+    parse: Duration,
+    solve: Duration,
+}
+
+impl Timing {
+    fn total(&self) -> Duration {
+        self.parse + self.solve
+    }
+}
+
+/// The two steps of a day run in the runner, each ending in the [Outcome] to report when it fails.
+trait Steps {
+    type Parsed;
+
+    fn parse_input(&self, input: &[u8]) -> Result<Self::Parsed, Outcome>;
+    fn solve_part(&self, parsed: &Self::Parsed, part: Part) -> Outcome;
+}
+
+impl Steps for Plugin {
+    type Parsed = Parsed;
+
+    fn parse_input(&self, input: &[u8]) -> Result<Parsed, Outcome> {
+        isolate_result(|| self.parse_bytes(input))
+    }
+
+    fn solve_part(&self, parsed: &Parsed, part: Part) -> Outcome {
+        isolate_result(|| self.solve(parsed, part == Part::Two)).map_or_else(|o| o, Outcome::Answered)
+    }
+}
+
+/// Parses the input once and computes the chosen parts from it, handing each to `each` as soon as
+/// it is done. Every part reports the shared parse time next to its own solve time.
+fn run_each<S: Steps, F: FnMut(Part, Outcome, Timing)>(steps: &S, input: &[u8], part: Part, mut each: F) {
+    let start = Instant::now();
+    let parsed = steps.parse_input(input);
+    let parse = start.elapsed();
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(outcome) => return part.parts().iter().for_each(|&p| each(p, outcome.clone(), Timing { parse, ..Timing::default() }))
+    };
+    for &p in part.parts() {
+        let start = Instant::now();
+        let outcome = steps.solve_part(&parsed, p);
+        each(p, outcome, Timing { parse, solve: start.elapsed() });
+    }
+}
+
+fn run<S: Steps>(steps: &S, input: &[u8], part: Part) -> Vec<(Part, Outcome, Timing)> {
+    let mut results = vec![];
+    run_each(steps, input, part, |p, outcome, timing| results.push((p, outcome, timing)));
+    results
+}
+
+/// Timing of a whole run, the parse counted once.
+fn combined(results: &[(Part, Outcome, Timing)]) -> Timing {
+    Timing {
+        parse: results.first().map(|(_, _, t)| t.parse).unwrap_or_default(),
+        solve: results.iter().map(|(_, _, t)| t.solve).sum(),
+    }
+}
+
+fn print_row(day: usize, part: &Part, outcome: &Outcome, timing: Option<Timing>) {
+    let (parse, solve) = timing.map_or(("-".to_string(), "-".to_string()),
+                                       |t| (format!("{:.3?}", t.parse), format!("{:.3?}", t.solve)));
+    let status = if timing.is_some() { outcome.status() } else { "input" };
+    println!("{:>3}  {:>4}  {:<7}  {:<20}  {:>12}  {:>12}", day, part, status, outcome.cell(), parse, solve);
+    if let Outcome::Answered(answer) = outcome {
+        if answer.is_multiline() {
+            answer.to_string().lines().for_each(|row| println!("{:>11}  {}", "", row));
+        }
+    }
+}
+
+/// What running one day produced, kept until it is that day's turn to be printed.
+struct DayRun {
+    day: usize,
+    results: Result<Vec<(Part, Outcome, Timing)>, String>,
+    log: Vec<String>,
+}
+
+fn run_all(solvers: &[Solver], year: u16, inputs_dir: &Path, format: Format, jobs: usize, limits: &Limits) {
+    let mut report = Report::new(format);
+    if format == Format::Plain {
+        println!("{:>3}  {:>4}  {:<7}  {:<20}  {:>12}  {:>12}", "day", "part", "status", "answer", "parse", "solve");
+    }
+    pool::ordered(solvers, jobs, |solver| {
+        let day = solver.day();
+        let source = Source::for_day(inputs_dir, year, day);
+        let (results, log) = capture(|| match source.read() {
+            Ok(input) => Ok(solver.run(&input, Part::Both, limits)),
+            Err(_) => Err(format!("missing {}", source)),
+        });
+        DayRun { day, results, log }
+    }, |DayRun { day, results, log }| {
+        log.iter().for_each(|line| eprintln!("{}", line));
+        match results {
+            Ok(results) => for (part, outcome, timing) in results {
+                match format {
+                    Format::Plain => print_row(day, &part, &outcome, Some(timing)),
+                    _ => report.write(&Record::new(day, part, &outcome, timing.parse, timing.solve)),
+                }
+            },
+            Err(missing) => for part in Part::Both.parts() {
+                match format {
+                    Format::Plain => print_row(day, part, &Outcome::Failed(missing.clone()), None),
+                    _ => report.write(&Record::missing(day, *part, missing.clone())),
+                }
+            },
+        }
+    });
+}
+
+fn list(solvers: &[Solver], year: u16, inputs_dir: &Path) {
+    let yes_no = |b: bool| if b { "yes" } else { "no" };
+    let solved = |solver: &Solver, n: usize| solver.implemented().map_or("?", |parts| yes_no(parts[n]));
+    println!("{:>3}  {:<26}  {:<8}  {:<3}  {:<3}  {:<5}  {:>8}", "day", "title", "kind", "one", "two", "input", "examples");
+    for solver in solvers {
+        let day = solver.day();
+        let input = matches!(Source::for_day(inputs_dir, year, day), Source::File(path) if path.is_file());
+        println!("{:>3}  {:<26}  {:<8}  {:<3}  {:<3}  {:<5}  {:>8}", day, solver.title().unwrap_or("-"), solver.kind(),
+                 solved(solver, 0), solved(solver, 1), yes_no(input), solver.examples().len());
+    }
+}
+
+/// Returns whether every part with a known answer still produces it.
+fn verify(answers: &Answers, solvers: &[Solver], year: u16, inputs_dir: &Path, limits: &Limits) -> bool {
+    let mut ok = true;
+    println!("{:>3}  {:>4}  {:<7}  {:<20}  {:<20}", "day", "part", "verdict", "expected", "actual");
+    for solver in solvers {
+        let day = solver.day();
+        let source = Source::for_day(inputs_dir, year, day);
+        let input = source.read();
+        let results = match &input {
+            Ok(input) if Part::Both.parts().iter().any(|&p| answers.get(year, day, p).is_some()) => solver.run(input, Part::Both, limits),
+            _ => vec![]
+        };
+        for &part in Part::Both.parts() {
+            let Some(expected) = answers.get(year, day, part) else {
+                println!("{:>3}  {:>4}  {:<7}  {:<20}  {:<20}", day, part, Verdict::Missing.label(), "-", "-");
+                continue
+            };
+            let (verdict, actual) = match results.iter().find(|(p, _, _)| *p == part) {
+                Some((_, outcome @ Outcome::Answered(_), _)) if outcome.to_string() == expected => (Verdict::Pass, outcome.cell()),
+                Some((_, outcome, _)) => (Verdict::Fail, outcome.cell()),
+                None => (Verdict::Missing, format!("missing {}", source))
+            };
+            ok &= verdict != Verdict::Fail;
+            println!("{:>3}  {:>4}  {:<7}  {:<20}  {:<20}", day, part, verdict.label(), expected, actual);
+        }
+    }
+    ok
+}
+
+/// Returns whether every example gives the answers it is known to give.
+fn examples(solver: Solver, day: usize, part: Part) -> Result<bool> {
+    if solver.examples().is_empty() {
+        return Err(Error::msg(format!("Day {} has no examples", day)))
+    }
+    let mut ok = true;
+    println!("{:>7}  {:>4}  {:<7}  {:<20}  {:<20}", "example", "part", "verdict", "expected", "actual");
+    for (n, example) in solver.examples().iter().enumerate() {
+        for (part, outcome, _) in solver.run(example.input.as_bytes(), part, &Limits::default()) {
+            let expected = if part == Part::Two { example.two } else { example.one };
+            let verdict = match expected {
+                Some(expected) if matches!(&outcome, Outcome::Answered(_)) && outcome.to_string() == expected => Verdict::Pass,
+                Some(_) => Verdict::Fail,
+                None => Verdict::Missing,
+            };
+            ok &= verdict != Verdict::Fail;
+            let expected = expected.map_or("-".to_string(), |e| Outcome::Answered(Answer::grid(e.lines())).cell());
+            println!("{:>7}  {:>4}  {:<7}  {:<20}  {:<20}", n + 1, part, verdict.label(), expected, outcome.cell());
+        }
+    }
+    Ok(ok)
+}
+
+/// Returns whether the solvers of a day all give the same answers.
+fn compare(variants: &[Solver], day: usize, part: Part, source: &Source, limits: &Limits) -> Result<bool> {
+    if variants.is_empty() {
+        return Err(Error::msg(format!("No solution for day {}, see list for the available ones", day)))
+    }
+    let input = source.read()?;
+    let results = variants.iter().map(|solver| (solver, solver.run(&input, part, limits))).collect::<Vec<_>>();
+    println!("{:<20}  {:<8}  {:>4}  {:<7}  {:<20}  {:>12}  {:>12}", "solver", "kind", "part", "status", "answer", "parse", "solve");
+    for (solver, results) in &results {
+        for (part, outcome, timing) in results {
+            println!("{:<20}  {:<8}  {:>4}  {:<7}  {:<20}  {:>12}  {:>12}", solver.label(), solver.kind(), part, outcome.status(), outcome.cell(),
+                     format!("{:.3?}", timing.parse), format!("{:.3?}", timing.solve));
+        }
+    }
+    let mut ok = true;
+    for (n, &part) in part.parts().iter().enumerate() {
+        let answers = results.iter().map(|(_, results)| &results[n].1).filter(|o| matches!(o, Outcome::Answered(_))).collect::<Vec<_>>();
+        if answers.windows(2).any(|w| w[0] != w[1]) {
+            println!("part {}: the answers differ", part);
+            ok = false;
+        }
+    }
+    Ok(ok)
+}
+
+struct BenchOptions {
+    runs: usize,
+    warmup: usize,
+    save: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    threshold: f64,
+}
+
+/// Returns whether the solution stayed within the threshold of the baseline.
+fn bench(solver: Solver, day: usize, part: Part, source: &Source, options: &BenchOptions) -> Result<bool> {
+    let input = source.read()?;
+    let mut samples = Vec::with_capacity(options.runs);
+    for n in 0..options.warmup + options.runs {
+        let results = solver.run(&input, part, &Limits::default());
+        for (part, outcome, _) in &results {
+            if let Outcome::Unimplemented | Outcome::Panicked(_) | Outcome::Failed(_) = outcome {
+                return Err(Error::msg(format!("Day {} part {} failed: {}", day, part, outcome)))
+            }
+        }
+        if n >= options.warmup {
+            samples.push(combined(&results));
+        }
+    }
+    let stats = Stats::from_samples(&samples.iter().map(Timing::total).collect::<Vec<_>>());
+    println!("day {} part {}: {}", day, part, stats);
+    println!("  parse: {}", Stats::from_samples(&samples.iter().map(|t| t.parse).collect::<Vec<_>>()));
+    println!("  solve: {}", Stats::from_samples(&samples.iter().map(|t| t.solve).collect::<Vec<_>>()));
+    let mut ok = true;
+    if let Some(path) = &options.baseline {
+        match Baseline::load(path)?.get(solver.year(), day, part) {
+            Some(baseline) if stats.regressed(baseline, options.threshold) => {
+                println!("REGRESSION: median {:.3?} vs baseline {:.3?}",
+                         Duration::from_nanos(stats.median_ns), Duration::from_nanos(baseline.median_ns));
+                ok = false;
+            }
+            Some(baseline) => println!("baseline median {:.3?}", Duration::from_nanos(baseline.median_ns)),
+            None => println!("no baseline for day {} part {}", day, part)
+        }
+    }
+    if let Some(path) = &options.save {
+        let mut saved = Baseline::load_or_default(path)?;
+        saved.insert(solver.year(), day, part, stats);
+        saved.save(path)?;
+    }
+    Ok(ok)
+}
+
+/// A compiled in day for the runner's child, which gets the path of a library instead.
+fn find_plugin(year: u16, day: usize) -> Result<&'static Plugin> {
+    inventory::iter::<Plugin>()
+        .find(|p| (p.year, p.day()) == (year, day))
+        .ok_or(Error::msg(format!("No compiled in solution for day {} of {}", day, year)))
+}
+
+fn main() -> Result<ExitCode> {
+    let args = Args::parse_from(std::env::args_os()).map_err(|e| Error::from(e))?;
+    Logger::new(args.verbose, args.quiet, args.log_day.clone()).install()?;
+    install_panic_hook();
+    let limits = Limits {
+        timeout: args.timeout.map(Duration::from_secs_f64),
+        memory: args.memory.map(|mib| mib << 20),
+        args: iter::repeat_n("-v".into(), args.verbose as usize)
+            .chain(iter::repeat_n("-q".into(), args.quiet as usize))
+            .chain(args.log_day.iter().flat_map(|day| ["--log-day".into(), day.to_string().into()]))
+            .chain(["--plugins-dir".into(), args.plugins_dir.clone().into()])
+            .chain(["--inputs-dir".into(), args.inputs_dir.clone().into()])
+            .collect(),
+    };
+    // a child is told which library to load, if any
+    let plugins = match args.command {
+        Some(Command::Child { .. }) => Plugins::default(),
+        _ => Plugins::discover(&args.plugins_dir)?,
+    };
+    let solvers = solvers(args.year, &plugins);
+    match args.command {
+        Some(Command::All { jobs }) => {
+            let jobs = jobs.unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()));
+            run_all(&solvers, args.year, &args.inputs_dir, args.format, jobs, &limits);
+            return Ok(ExitCode::SUCCESS)
+        }
+        Some(Command::List) => {
+            list(&solvers, args.year, &args.inputs_dir);
+            return Ok(ExitCode::SUCCESS)
+        }
+        Some(Command::Verify { answers }) => {
+            let answers = Answers::load(&answers)?;
+            return Ok(if verify(&answers, &solvers, args.year, &args.inputs_dir, &limits) { ExitCode::SUCCESS } else { ExitCode::FAILURE })
+        }
+        Some(Command::Bench { day, part, input, runs, warmup, save, baseline, threshold }) => {
+            let options = BenchOptions { runs, warmup, save, baseline, threshold };
+            let source = Source::choose(input.as_deref(), &args.inputs_dir, args.year, day);
+            let ok = bench(solver::find(args.year, day, &plugins)?, day, part.unwrap_or_default(), &source, &options)?;
+            return Ok(if ok { ExitCode::SUCCESS } else { ExitCode::FAILURE })
+        }
+        Some(Command::Compare { day, part, input }) => {
+            let source = Source::choose(input.as_deref(), &args.inputs_dir, args.year, day);
+            let variants = variants(args.year, &plugins).into_iter().filter(|s| s.day() == day).collect::<Vec<_>>();
+            let ok = compare(&variants, day, part.unwrap_or(Part::Both), &source, &limits)?;
+            return Ok(if ok { ExitCode::SUCCESS } else { ExitCode::FAILURE })
+        }
+        Some(Command::Child { part, library: Some(path), .. }) => {
+            child::serve(&Library::load(&path)?, part)?;
+            return Ok(ExitCode::SUCCESS)
+        }
+        Some(Command::Child { day, part, library: None }) => {
+            child::serve(find_plugin(args.year, day)?, part)?;
+            return Ok(ExitCode::SUCCESS)
+        }
+        Some(Command::New { day }) => {
+            let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+            for path in Scaffold::new(&src, &args.inputs_dir).create(args.year, day)? {
+                println!("created {}", path.display());
+            }
+            return Ok(ExitCode::SUCCESS)
+        }
+        None => ()
+    }
+    let day = args.day.ok_or(Error::msg("No day given"))?;
+    let part = args.part.unwrap_or_default();
+    let solver = solver::find(args.year, day, &plugins)?;
+    if args.example {
+        return Ok(if examples(solver, day, part)? { ExitCode::SUCCESS } else { ExitCode::FAILURE })
+    }
+    let source = Source::choose(args.input.as_deref(), &args.inputs_dir, args.year, day);
+    let results = solver.run(&source.read()?, part, &limits);
+    if args.format != Format::Plain {
+        let mut report = Report::new(args.format);
+        results.iter().for_each(|(part, outcome, timing)| report.write(&Record::new(day, *part, outcome, timing.parse, timing.solve)));
+        let ok = results.iter().all(|(_, outcome, _)| matches!(outcome, Outcome::Answered(_)));
+        return Ok(if ok { ExitCode::SUCCESS } else { ExitCode::FAILURE })
+    }
+    for (part, outcome, _) in results {
+        match outcome {
+            Outcome::Answered(res) => println!("{}", res),
+            Outcome::Unimplemented => return Err(Error::msg(format!("Day {} part {} is not implemented", day, part))),
+            Outcome::Panicked(msg) => return Err(Error::msg(format!("Day {} part {} panicked: {}", day, part, msg))),
+            Outcome::Failed(msg) => return Err(Error::msg(msg)),
+            outcome @ (Outcome::TimedOut(_) | Outcome::OutOfMemory(_)) =>
+                return Err(Error::msg(format!("Day {} part {} {}", day, part, outcome))),
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn global_flags_before_a_command() {
+        let args = Args::parse_from(["runner", "--year", "2021", "list"]).unwrap();
+        assert!(matches!(args.command, Some(Command::List)));
+        assert_eq!(2021, args.year);
+        let args = Args::parse_from(["runner", "-q", "all", "--jobs", "2"]).unwrap();
+        assert!(matches!(args.command, Some(Command::All { jobs: Some(2) })));
+        assert_eq!(1, args.quiet);
+        assert_eq!(Some(5), Args::parse_from(["runner", "--year", "2021", "5"]).unwrap().day);
+        assert!(Args::parse_from(["runner", "5", "list"]).is_err());
+    }
+
+    #[test]
+    fn format_only_for_results() {
+        assert_eq!(Format::Json, Args::parse_from(["runner", "--format", "json", "all"]).unwrap().format);
+        assert_eq!(Format::Csv, Args::parse_from(["runner", "5", "--format", "csv"]).unwrap().format);
+        assert!(Args::parse_from(["runner", "verify", "--format", "json"]).is_err());
+        assert!(Args::parse_from(["runner", "--format", "csv", "compare", "13"]).is_err());
+    }
+}
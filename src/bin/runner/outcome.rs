@@ -0,0 +1,109 @@
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::fmt::{Display, Formatter};
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use aoc_2022::Answer;
+
+/// What came out of a single solution step.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Outcome {
+    Answered(Answer),
+    Unimplemented,
+    Panicked(String),
+    Failed(String),
+    /// killed after running for longer than the timeout
+    TimedOut(Duration),
+    /// ran out of the address space it was allowed, in bytes
+    OutOfMemory(u64),
+}
+
+impl Outcome {
+    /// Single line form for the tables, pictures are printed below them.
+    pub fn cell(&self) -> String {
+        match self {
+            Outcome::Answered(Answer::Grid(rows)) if rows.len() > 1 =>
+                format!("{}x{} picture", rows.first().map(|r| r.len()).unwrap_or_default(), rows.len()),
+            outcome => outcome.to_string()
+        }
+    }
+
+    pub fn status(&self) -> &'static str {
+        match self {
+            Outcome::Answered(_) => "ok",
+            Outcome::Unimplemented => "todo",
+            Outcome::Panicked(_) => "panic",
+            Outcome::Failed(_) => "error",
+            Outcome::TimedOut(_) => "timeout",
+            Outcome::OutOfMemory(_) => "oom",
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Answered(answer) => write!(f, "{}", answer),
+            Outcome::Unimplemented => write!(f, "not implemented"),
+            Outcome::Panicked(msg) => write!(f, "{}", msg),
+            Outcome::Failed(msg) => write!(f, "{}", msg),
+            Outcome::TimedOut(limit) => write!(f, "timed out after {:?}", limit),
+            Outcome::OutOfMemory(limit) => write!(f, "out of memory, limit {} MiB", limit >> 20),
+        }
+    }
+}
+
+thread_local! {
+    static IN_HANDLER: Cell<bool> = const { Cell::new(false) };
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Keeps panics raised inside a handler quiet and remembers where they happened,
+/// everything else goes to the default hook.
+pub fn install_panic_hook() {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if IN_HANDLER.with(|h| h.get()) {
+            let location = info.location().map(|l| format!(" at {}:{}", l.file(), l.line())).unwrap_or_default();
+            LAST_PANIC.with(|p| *p.borrow_mut() = Some(location));
+        } else {
+            default_hook(info)
+        }
+    }));
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload.downcast_ref::<&str>().map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
+}
+
+/// The [Outcome] of a panic with this message, `todo!()` and `unimplemented!()` are no failures.
+pub fn panicked(msg: String) -> Outcome {
+    if msg.starts_with("not yet implemented") || msg.starts_with("not implemented") {
+        Outcome::Unimplemented
+    } else {
+        Outcome::Panicked(msg)
+    }
+}
+
+fn classify(payload: Box<dyn Any + Send>) -> Outcome {
+    let location = LAST_PANIC.with(|p| p.borrow_mut().take()).unwrap_or_default();
+    panicked(format!("{}{}", panic_message(payload.as_ref()), location))
+}
+
+/// Runs a step of a solution, turning `todo!()` and panics into the [Outcome] to report.
+pub fn isolate<T, F: FnOnce() -> T>(f: F) -> Result<T, Outcome> {
+    IN_HANDLER.with(|h| h.set(true));
+    let res = panic::catch_unwind(AssertUnwindSafe(f));
+    IN_HANDLER.with(|h| h.set(false));
+    res.map_err(classify)
+}
+
+/// Like [isolate] for steps that report errors, those end up as [Outcome::Failed] with their whole context.
+pub fn isolate_result<T, F: FnOnce() -> anyhow::Result<T>>(f: F) -> Result<T, Outcome> {
+    isolate(f)?.map_err(|e| Outcome::Failed(format!("{:#}", e)))
+}
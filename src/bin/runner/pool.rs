@@ -0,0 +1,55 @@
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+/// Applies `work` to every item on up to `jobs` threads and hands the results to `emit` in the
+/// order of the items, each as soon as it and all the ones before it are done.
+pub fn ordered<T, R, W, E>(items: &[T], jobs: usize, work: W, mut emit: E)
+    where T: Sync, R: Send, W: Fn(&T) -> R + Sync, E: FnMut(R) {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            let sender = sender.clone();
+            let (next, work) = (&next, &work);
+            scope.spawn(move || {
+                loop {
+                    let n = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(n) else { break };
+                    if sender.send((n, work(item))).is_err() {
+                        break
+                    }
+                }
+            });
+        }
+        drop(sender);
+        let mut pending = BTreeMap::new();
+        let mut first = 0;
+        for (n, result) in receiver {
+            pending.insert(n, result);
+            while let Some(result) = pending.remove(&first) {
+                emit(result);
+                first += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn keeps_order() {
+        let items = (0..20_u64).collect::<Vec<_>>();
+        let mut out = vec![];
+        ordered(&items, 4, |&n| {
+            thread::sleep(Duration::from_millis((20 - n) % 7));
+            n * 2
+        }, |r| out.push(r));
+        assert_eq!(items.iter().map(|n| n * 2).collect::<Vec<_>>(), out);
+    }
+}
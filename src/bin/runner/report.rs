@@ -0,0 +1,122 @@
+use std::time::Duration;
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use aoc_2022::Answer;
+
+use crate::outcome::Outcome;
+use crate::Part;
+
+/// How results are written to stdout.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// the bare answer, or a table for all
+    #[default]
+    Plain,
+    /// one JSON object per line
+    Json,
+    /// comma separated values with a header line
+    Csv,
+}
+
+/// Result of one part in the machine readable formats.
+#[derive(Debug, Serialize)]
+pub struct Record<'a> {
+    pub day: usize,
+    pub part: usize,
+    pub status: &'static str,
+    pub answer: Option<&'a Answer>,
+    pub error: Option<String>,
+    pub parse_ns: Option<u64>,
+    pub solve_ns: Option<u64>,
+}
+
+impl<'a> Record<'a> {
+    pub fn new(day: usize, part: Part, outcome: &'a Outcome, parse: Duration, solve: Duration) -> Self {
+        let (answer, error) = match outcome {
+            Outcome::Answered(answer) => (Some(answer), None),
+            outcome => (None, Some(outcome.to_string())),
+        };
+        Record {
+            day, part: part as usize, status: outcome.status(), answer, error,
+            parse_ns: Some(parse.as_nanos() as u64), solve_ns: Some(solve.as_nanos() as u64),
+        }
+    }
+
+    /// A part that could not run because its input is missing.
+    pub fn missing(day: usize, part: Part, error: String) -> Self {
+        Record { day, part: part as usize, status: "input", answer: None, error: Some(error), parse_ns: None, solve_ns: None }
+    }
+}
+
+const CSV_HEADER: &str = "day,part,status,answer,error,parse_ns,solve_ns";
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn csv_line(record: &Record) -> String {
+    let number = |n: Option<u64>| n.map(|n| n.to_string()).unwrap_or_default();
+    [
+        record.day.to_string(),
+        record.part.to_string(),
+        record.status.to_string(),
+        csv_field(&record.answer.map(|a| a.to_string()).unwrap_or_default()),
+        csv_field(record.error.as_deref().unwrap_or_default()),
+        number(record.parse_ns),
+        number(record.solve_ns),
+    ].join(",")
+}
+
+/// Writes records in one of the machine readable formats, [Format::Plain] is left to the caller.
+pub struct Report {
+    format: Format,
+    started: bool,
+}
+
+impl Report {
+    pub fn new(format: Format) -> Self {
+        Report { format, started: false }
+    }
+
+    pub fn line(&mut self, record: &Record) -> String {
+        let header = !std::mem::replace(&mut self.started, true);
+        match self.format {
+            Format::Csv if header => format!("{}\n{}", CSV_HEADER, csv_line(record)),
+            Format::Csv => csv_line(record),
+            _ => serde_json::to_string(record).unwrap_or_default(),
+        }
+    }
+
+    pub fn write(&mut self, record: &Record) {
+        println!("{}", self.line(record));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json() {
+        let outcome = Outcome::Answered(Answer::Integer(24000));
+        let record = Record::new(1, Part::One, &outcome, Duration::from_nanos(5), Duration::from_nanos(7));
+        assert_eq!(r#"{"day":1,"part":1,"status":"ok","answer":{"kind":"integer","value":24000},"error":null,"parse_ns":5,"solve_ns":7}"#,
+                   Report::new(Format::Json).line(&record));
+    }
+
+    #[test]
+    fn csv() {
+        let mut report = Report::new(Format::Csv);
+        let outcome = Outcome::Answered(Answer::grid(["#.", ".#"]));
+        let record = Record::new(10, Part::Two, &outcome, Duration::from_nanos(5), Duration::from_nanos(7));
+        assert_eq!("day,part,status,answer,error,parse_ns,solve_ns\n10,2,ok,\"#.\n.#\",,5,7", report.line(&record));
+        let record = Record::missing(11, Part::One, "missing \"11.txt\"".to_string());
+        assert_eq!("11,1,input,,\"missing \"\"11.txt\"\"\",,", report.line(&record));
+    }
+}
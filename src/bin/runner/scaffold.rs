@@ -0,0 +1,115 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Error, Result};
+
+use aoc_2022::DEFAULT_YEAR;
+
+use crate::input::Source;
+
+/// Where a new day goes: the crate's sources and the directory of the inputs.
+pub struct Scaffold {
+    src: PathBuf,
+    inputs_dir: PathBuf,
+}
+
+impl Scaffold {
+    pub fn new(src: &Path, inputs_dir: &Path) -> Self {
+        Scaffold { src: src.to_path_buf(), inputs_dir: inputs_dir.to_path_buf() }
+    }
+
+    /// Adds day `day` of `year` from the `s00` template and returns the files it created. Days of
+    /// the default year go straight into `solutions`, the others into a `yYYYY` module of their own.
+    pub fn create(&self, year: u16, day: usize) -> Result<Vec<PathBuf>> {
+        if !(1..=25).contains(&day) {
+            return Err(Error::msg(format!("There is no day {}", day)))
+        }
+        let name = format!("s{:02}", day);
+        let solutions = self.src.join("solutions");
+        let (dir, parent, registration) = if year == DEFAULT_YEAR {
+            (solutions.clone(), self.src.join("solutions.rs"), format!("solution!({});", name))
+        } else {
+            let module = format!("y{}", year);
+            (solutions.join(&module), solutions.join(format!("{}.rs", module)), format!("solution!({}, {}::{});", year, module, name))
+        };
+        let module = dir.join(format!("{}.rs", name));
+        if module.exists() {
+            return Err(Error::msg(format!("Day {} of {} already exists in {}", day, year, module.display())))
+        }
+        let lib = self.src.join("lib.rs");
+        let parent_text = insert_sorted(&read_or_empty(&parent)?, &format!("pub mod {};", name), "pub mod ")
+            .with_context(|| parent.display().to_string())?;
+        let lib_text = insert_sorted(&read(&lib)?, &registration, "solution!(")
+            .with_context(|| lib.display().to_string())?;
+        let template = read(&solutions.join("s00.rs"))?;
+
+        let mut created = vec![];
+        if dir != solutions {
+            let root = self.src.join("solutions.rs");
+            let line = format!("pub mod y{};", year);
+            let text = read(&root)?;
+            if !text.lines().any(|l| l == line) {
+                write(&root, &insert_sorted(&text, &line, "pub mod ")?)?;
+            }
+            if !parent.exists() {
+                created.push(parent.clone());
+            }
+            fs::create_dir_all(&dir).with_context(|| dir.display().to_string())?;
+        }
+        write(&module, &template)?;
+        created.push(module);
+        write(&parent, &parent_text)?;
+        write(&lib, &lib_text)?;
+        if let Source::File(input) = Source::for_day(&self.inputs_dir, year, day) {
+            if !input.exists() {
+                if let Some(dir) = input.parent() {
+                    fs::create_dir_all(dir).with_context(|| dir.display().to_string())?;
+                }
+                write(&input, "")?;
+                created.push(input);
+            }
+        }
+        Ok(created)
+    }
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| path.display().to_string())
+}
+
+fn read_or_empty(path: &Path) -> Result<String> {
+    if path.exists() { read(path) } else { Ok(String::new()) }
+}
+
+fn write(path: &Path, text: &str) -> Result<()> {
+    fs::write(path, text).with_context(|| path.display().to_string())
+}
+
+/// Adds `line` to the lines starting with `prefix`, which end up sorted in place of the first of them.
+fn insert_sorted(text: &str, line: &str, prefix: &str) -> Result<String> {
+    let mut entries = text.lines().filter(|l| l.starts_with(prefix)).collect::<Vec<_>>();
+    if entries.contains(&line) {
+        return Err(Error::msg(format!("{} is already there", line)))
+    }
+    let first = text.lines().position(|l| l.starts_with(prefix));
+    entries.push(line);
+    entries.sort_unstable();
+    let mut lines = text.lines().filter(|l| !l.starts_with(prefix)).collect::<Vec<_>>();
+    let at = first.unwrap_or(lines.len());
+    lines.splice(at..at, entries);
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorted() {
+        let text = "use x;\n\nsolution!(s18);\nsolution!(s17);\n\nfn f() {}\n";
+        assert_eq!("use x;\n\nsolution!(s13);\nsolution!(s17);\nsolution!(s18);\n\nfn f() {}\n",
+                   insert_sorted(text, "solution!(s13);", "solution!(s").unwrap());
+        assert_eq!("pub mod s01;\n", insert_sorted("", "pub mod s01;", "pub mod s").unwrap());
+        assert!(insert_sorted(text, "solution!(s17);", "solution!(s").is_err());
+    }
+}
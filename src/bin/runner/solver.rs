@@ -0,0 +1,128 @@
+use std::path::Path;
+
+use anyhow::{Error, Result};
+use itertools::Itertools;
+
+use aoc_2022::{Example, Plugin};
+
+use crate::child::{self, Limits};
+use crate::external::External;
+use crate::library::Library;
+use crate::outcome::Outcome;
+use crate::{run, Part, Timing};
+
+/// What the plugins directory has to offer.
+#[derive(Default)]
+pub struct Plugins {
+    pub libraries: Vec<Library>,
+    pub externals: Vec<External>,
+}
+
+impl Plugins {
+    pub fn discover(dir: &Path) -> Result<Plugins> {
+        Ok(Plugins { libraries: Library::discover(dir)?, externals: External::discover(dir)? })
+    }
+}
+
+/// A day the runner can run: compiled into the crate, loaded from a shared library or an
+/// executable from the plugins directory.
+#[derive(Clone, Copy)]
+pub enum Solver<'a> {
+    Builtin(&'static Plugin),
+    Library(&'a Library),
+    External(&'a External),
+}
+
+impl<'a> Solver<'a> {
+    pub fn year(&self) -> u16 {
+        match self {
+            Solver::Builtin(plugin) => plugin.year,
+            Solver::Library(library) => library.year,
+            Solver::External(external) => external.year,
+        }
+    }
+
+    pub fn day(&self) -> usize {
+        match self {
+            Solver::Builtin(plugin) => plugin.day(),
+            Solver::Library(library) => library.day,
+            Solver::External(external) => external.day,
+        }
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Solver::Builtin(_) => "rust",
+            Solver::Library(_) => "library",
+            Solver::External(_) => "external",
+        }
+    }
+
+    /// Tells apart the solvers of a day, a plugin by its file.
+    pub fn label(&self) -> String {
+        let file = |path: &Path| path.file_name().map_or(String::new(), |n| n.to_string_lossy().to_string());
+        match self {
+            Solver::Builtin(_) => "compiled in".to_string(),
+            Solver::Library(library) => file(&library.path),
+            Solver::External(external) => file(&external.path),
+        }
+    }
+
+    pub fn title(&self) -> Option<&'a str> {
+        match self {
+            Solver::Builtin(plugin) => plugin.title,
+            Solver::Library(library) => library.title.as_deref(),
+            Solver::External(_) => None,
+        }
+    }
+
+    /// Which parts are solved, unknown for an executable.
+    pub fn implemented(&self) -> Option<[bool; 2]> {
+        match self {
+            Solver::Builtin(plugin) => Some(plugin.implemented),
+            Solver::Library(library) => Some(library.implemented),
+            Solver::External(_) => None,
+        }
+    }
+
+    pub fn examples(&self) -> &'static [Example] {
+        match self {
+            Solver::Builtin(plugin) => plugin.examples,
+            Solver::Library(_) | Solver::External(_) => &[],
+        }
+    }
+
+    /// Computes the chosen parts, in a child process when a Rust day has limits to keep.
+    pub fn run(&self, input: &[u8], part: Part, limits: &Limits) -> Vec<(Part, Outcome, Timing)> {
+        match self {
+            Solver::Builtin(_) | Solver::Library(_) if limits.any() => child::run(self, input, part, limits),
+            Solver::Builtin(plugin) => run(*plugin, input, part),
+            Solver::Library(library) => run(*library, input, part),
+            Solver::External(external) => external.run(input, part, limits),
+        }
+    }
+}
+
+/// Every solver of `year` by day, for the same day a library before the compiled in day before an executable.
+pub fn variants(year: u16, plugins: &Plugins) -> Vec<Solver<'_>> {
+    plugins.libraries.iter().map(Solver::Library)
+        .chain(inventory::iter::<Plugin>().map(Solver::Builtin))
+        .chain(plugins.externals.iter().map(Solver::External))
+        .filter(|s| s.year() == year)
+        .sorted_by_key(|s| s.day())
+        .collect()
+}
+
+/// The days of `year` by day, each with the first of its [variants]: a library swaps out the
+/// compiled in day, which hides an executable.
+pub fn solvers(year: u16, plugins: &Plugins) -> Vec<Solver<'_>> {
+    variants(year, plugins).into_iter()
+        .dedup_by(|a, b| a.day() == b.day())
+        .collect()
+}
+
+pub fn find(year: u16, day: usize, plugins: &Plugins) -> Result<Solver<'_>> {
+    solvers(year, plugins).into_iter()
+        .find(|s| s.day() == day)
+        .ok_or(Error::msg(format!("No solution for day {} of {}, see list for the available ones", day, year)))
+}
@@ -0,0 +1,59 @@
+use fast_paths::{FastGraph, InputGraph};
+
+/// A directed graph of numbered nodes with weighted edges, prepared once for any number of
+/// shortest path queries.
+pub struct Graph {
+    graph: FastGraph,
+}
+
+impl Graph {
+    /// The graph of the `(from, to, weight)` edges, nodes are numbered from zero up to the
+    /// largest one an edge mentions.
+    pub fn new<I: IntoIterator<Item = (usize, usize, usize)>>(edges: I) -> Self {
+        let mut input_graph = InputGraph::new();
+        edges.into_iter().for_each(|(from, to, weight)| { input_graph.add_edge(from, to, weight); });
+        input_graph.freeze();
+        Graph { graph: fast_paths::prepare(&input_graph) }
+    }
+
+    fn has(&self, node: usize) -> bool {
+        node < self.graph.get_num_nodes()
+    }
+
+    /// Length of the shortest path from `from` to `to`, `None` when there is none.
+    pub fn distance(&self, from: usize, to: usize) -> Option<usize> {
+        if from == to {
+            return Some(0)
+        }
+        if !self.has(from) || !self.has(to) {
+            return None
+        }
+        fast_paths::calc_path(&self.graph, from, to)
+            .filter(|shortest_path| shortest_path.is_found())
+            .map(|shortest_path| shortest_path.get_weight())
+    }
+
+    /// The distances between every two of `nodes`, by their position in `nodes`.
+    pub fn distances(&self, nodes: &[usize]) -> Vec<Vec<Option<usize>>> {
+        let mut calculator = fast_paths::create_calculator(&self.graph);
+        nodes.iter().map(|&from| nodes.iter().map(|&to| if from == to {
+            Some(0)
+        } else if !self.has(from) || !self.has(to) {
+            None
+        } else {
+            calculator.calc_path(&self.graph, from, to)
+                .filter(|shortest_path| shortest_path.is_found())
+                .map(|shortest_path| shortest_path.get_weight())
+        }).collect()).collect()
+    }
+}
+
+#[test]
+fn shortest() {
+    // 0 -> 1 -> 2 -> 3 and a longer way round from 0 to 3, nothing leads back
+    let graph = Graph::new([(0, 1, 1), (1, 2, 1), (2, 3, 1), (0, 3, 5)]);
+    assert_eq!(Some(3), graph.distance(0, 3));
+    assert_eq!(None, graph.distance(3, 0));
+    assert_eq!(None, graph.distance(0, 7));
+    assert_eq!(vec![vec![Some(0), Some(2)], vec![None, Some(0)]], graph.distances(&[1, 3]));
+}
@@ -0,0 +1,89 @@
+use std::ops::RangeInclusive;
+
+/// A set of integers kept as sorted, disjoint closed intervals. Intervals that overlap or touch
+/// are merged as they are inserted, so there is never a gap of zero integers between two.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Intervals {
+    /// `(start, end)`, both included
+    ranges: Vec<(i64, i64)>,
+}
+
+impl Intervals {
+    pub fn new() -> Self {
+        Intervals::default()
+    }
+
+    /// Adds every integer of `range`, an empty range adds nothing.
+    pub fn insert(&mut self, range: RangeInclusive<i64>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return
+        }
+        let first = self.ranges.partition_point(|&(_, e)| e < start.saturating_sub(1));
+        let last = self.ranges.partition_point(|&(s, _)| s <= end.saturating_add(1));
+        if first < last {
+            start = start.min(self.ranges[first].0);
+            end = end.max(self.ranges[last - 1].1);
+        }
+        self.ranges.splice(first..last, [(start, end)]);
+    }
+
+    pub fn contains(&self, n: i64) -> bool {
+        let i = self.ranges.partition_point(|&(_, e)| e < n);
+        self.ranges.get(i).is_some_and(|&(s, _)| s <= n)
+    }
+
+    /// The number of integers in the set.
+    pub fn len(&self) -> u64 {
+        self.ranges.iter().map(|&(s, e)| (e - s) as u64 + 1).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<i64>> + '_ {
+        self.ranges.iter().map(|&(s, e)| s..=e)
+    }
+
+    /// The smallest integer of `within` that is not in the set.
+    pub fn first_gap(&self, within: RangeInclusive<i64>) -> Option<i64> {
+        let (start, end) = within.into_inner();
+        let mut n = start;
+        let first = self.ranges.partition_point(|&(_, e)| e < start);
+        for &(s, e) in &self.ranges[first..] {
+            if s > n {
+                break
+            }
+            n = e.checked_add(1)?;
+        }
+        (n <= end).then_some(n)
+    }
+}
+
+impl FromIterator<RangeInclusive<i64>> for Intervals {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<i64>>>(iter: I) -> Self {
+        let mut intervals = Intervals::new();
+        iter.into_iter().for_each(|r| intervals.insert(r));
+        intervals
+    }
+}
+
+#[test]
+fn merging() {
+    let intervals = [12..=12, 2..=14, -2..=2, 16..=24, 15..=15].into_iter().collect::<Intervals>();
+    assert_eq!(vec![-2..=24], intervals.iter().collect::<Vec<_>>());
+    assert_eq!(27, intervals.len());
+    let intervals = [5..=7, 0..=1, 3..=3].into_iter().collect::<Intervals>();
+    assert_eq!(vec![0..=1, 3..=3, 5..=7], intervals.iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn gaps() {
+    let intervals = [0..=3, 5..=9].into_iter().collect::<Intervals>();
+    assert!(intervals.contains(3) && !intervals.contains(4) && !intervals.contains(10));
+    assert_eq!(Some(4), intervals.first_gap(0..=9));
+    assert_eq!(Some(-1), intervals.first_gap(-1..=9));
+    assert_eq!(None, intervals.first_gap(5..=9));
+    assert_eq!(Some(10), intervals.first_gap(6..=20));
+}
@@ -1,9 +1,20 @@
+use std::any::Any;
 use std::borrow::Cow;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
 
+use anyhow::{Context, Result};
 use inventory::submit;
 
+pub use answer::Answer;
+
+pub mod abi;
+pub mod answer;
+pub mod graph;
+pub mod intervals;
 pub mod solutions;
 pub mod parsers;
+pub mod search;
 
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Coord {
@@ -13,18 +24,165 @@ pub struct Coord {
 
 pub type Ztr = Cow<'static, str>;
 pub type InputIterator<'a> = &'a mut dyn Iterator<Item = Ztr>;
-pub type Handler = fn(InputIterator, bool) -> Ztr;
-pub struct Plugin(pub &'static str, pub Handler);
+
+/// A line of the puzzle input that could not be understood.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputError {
+    /// 1-based, as shown by editors
+    pub line: usize,
+    pub text: String,
+    pub reason: String,
+}
+
+impl InputError {
+    /// `index` is the 0-based position of the line, as handed out by `enumerate()`.
+    pub fn new(index: usize, text: &str, reason: impl Into<String>) -> Self {
+        InputError { line: index + 1, text: text.to_string(), reason: reason.into() }
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}: {:?}", self.line, self.reason, self.text)
+    }
+}
+
+impl Error for InputError {}
+
+/// Splits raw input the way [str::lines] does: on `\n`, dropping a `\r` before it and the empty
+/// piece after a final newline.
+pub fn byte_lines(bytes: &[u8]) -> impl Iterator<Item = &[u8]> {
+    let empty = bytes.is_empty();
+    bytes.strip_suffix(b"\n").unwrap_or(bytes)
+         .split(|&b| b == b'\n')
+         .filter(move |_| !empty)
+         .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+}
+
+/// Decodes raw input into lines, the first line that isn't UTF-8 is an error.
+pub fn decode_lines(bytes: &[u8]) -> Result<Vec<Ztr>, InputError> {
+    byte_lines(bytes)
+        .enumerate()
+        .map(|(n, line)| std::str::from_utf8(line)
+            .map(|l| Ztr::from(l.to_string()))
+            .map_err(|e| InputError::new(n, &String::from_utf8_lossy(line), format!("invalid UTF-8 at byte {}", e.valid_up_to()))))
+        .collect()
+}
+
+/// A sample input from the puzzle text and the answers it is known to give, `None` for a part
+/// the sample doesn't cover or the day doesn't solve yet.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: &'static str,
+    pub one: Option<&'static str>,
+    pub two: Option<&'static str>,
+}
+
+/// One day of the contest. The input is parsed once and both parts are computed from the result.
+pub trait Solution {
+    type Input: 'static;
+    type One: Into<Answer>;
+    type Two: Into<Answer>;
+
+    /// Name of the puzzle, shown by the runner's `list`.
+    const TITLE: Option<&'static str> = None;
+    /// Which of part one and part two are solved, the others are still `todo!()`.
+    const IMPLEMENTED: [bool; 2] = [true, true];
+    /// Samples run by the tests of every day and by the runner's `--example`.
+    const EXAMPLES: &'static [Example] = &[];
+
+    fn parse(it: InputIterator) -> Result<Self::Input>;
+
+    /// Parses the whole input file. By default it is decoded into lines for [Solution::parse],
+    /// days whose parsers work on `&[u8]` anyway can take the bytes as they are.
+    fn parse_bytes(bytes: &[u8]) -> Result<Self::Input> {
+        Self::parse(&mut decode_lines(bytes)?.into_iter())
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::One>;
+    fn part_two(input: &Self::Input) -> Result<Self::Two>;
+}
+
+/// Parsed input of some [Solution], only the plugin that produced it knows the concrete type.
+pub type Parsed = Box<dyn Any>;
+
+/// The event the plain `solution!(sNN)` registrations belong to.
+pub const DEFAULT_YEAR: u16 = 2022;
+
+/// Type erased [Solution], this is what gets registered with `inventory`.
+pub struct Plugin {
+    pub year: u16,
+    pub name: &'static str,
+    pub title: Option<&'static str>,
+    pub implemented: [bool; 2],
+    pub examples: &'static [Example],
+    parse: fn(InputIterator) -> Result<Parsed>,
+    parse_bytes: fn(&[u8]) -> Result<Parsed>,
+    solve: fn(&Parsed, bool) -> Result<Answer>,
+}
+
+fn parse<S: Solution>(it: InputIterator) -> Result<Parsed> {
+    Ok(Box::new(S::parse(it)?))
+}
+
+fn parse_bytes<S: Solution>(bytes: &[u8]) -> Result<Parsed> {
+    Ok(Box::new(S::parse_bytes(bytes)?))
+}
+
+fn solve<S: Solution>(input: &Parsed, part_two: bool) -> Result<Answer> {
+    let input = input.downcast_ref::<S::Input>().context("input parsed by a different solution")?;
+    Ok(if part_two {
+        S::part_two(input)?.into()
+    } else {
+        S::part_one(input)?.into()
+    })
+}
+
+impl Plugin {
+    pub const fn new<S: Solution>(year: u16, name: &'static str) -> Self {
+        Plugin { year, name, title: S::TITLE, implemented: S::IMPLEMENTED, examples: S::EXAMPLES, parse: parse::<S>, parse_bytes: parse_bytes::<S>, solve: solve::<S> }
+    }
+
+    /// The day of the event, from the `sNN` module name.
+    pub fn day(&self) -> usize {
+        self.name.trim_start_matches('s').parse().unwrap_or_default()
+    }
+
+    pub fn parse(&self, it: InputIterator) -> Result<Parsed> {
+        (self.parse)(it).with_context(|| format!("day {:02}: bad input", self.day()))
+    }
+
+    pub fn parse_bytes(&self, bytes: &[u8]) -> Result<Parsed> {
+        (self.parse_bytes)(bytes).with_context(|| format!("day {:02}: bad input", self.day()))
+    }
+
+    pub fn solve(&self, input: &Parsed, part_two: bool) -> Result<Answer> {
+        (self.solve)(input, part_two).with_context(|| format!("day {:02} part {}", self.day(), if part_two { 2 } else { 1 }))
+    }
+
+    /// Parses the input and computes one part of it.
+    pub fn run(&self, it: InputIterator, part_two: bool) -> Result<Answer> {
+        self.solve(&self.parse(it)?, part_two)
+    }
+}
 
 inventory::collect!(Plugin);
 
+/// Registers `solutions::sNN` for [DEFAULT_YEAR], or `solutions::yYYYY::sNN` as `solution!(YYYY, yYYYY::sNN)`.
 macro_rules! solution {
-    ($name:tt) => {
+    ($name:ident) => {
+        solution!($crate::DEFAULT_YEAR, $name);
+    };
+    ($year:expr, $name:ident) => {
         $crate::submit! {
-            use crate::solutions::$name::solution;
-            $crate::Plugin(stringify!($name), solution)
+            $crate::Plugin::new::<crate::solutions::$name::Day>($year, stringify!($name))
         }
-    }
+    };
+    ($year:expr, $module:ident :: $name:ident) => {
+        $crate::submit! {
+            $crate::Plugin::new::<crate::solutions::$module::$name::Day>($year, stringify!($name))
+        }
+    };
 }
 
 solution!(s00);
@@ -40,10 +198,55 @@ solution!(s09);
 solution!(s10);
 solution!(s11);
 solution!(s12);
+solution!(s13);
 solution!(s14);
-solution!(s18);
+solution!(s15);
+solution!(s16);
 solution!(s17);
+solution!(s18);
+solution!(s19);
+solution!(s20);
+solution!(s21);
+solution!(s22);
 solution!(s23);
 solution!(s24);
-solution!(s22);
-solution!(s21);
+solution!(s25);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_of_bytes() {
+        assert_eq!(vec![&b"a"[..], b"", b"b"], byte_lines(b"a\r\n\nb\n").collect::<Vec<_>>());
+        assert_eq!(0, byte_lines(b"").count());
+        assert_eq!(1, byte_lines(b"\n").count());
+    }
+
+    #[test]
+    fn bad_utf8() {
+        let err = decode_lines(b"ok\nnot \xff ok").unwrap_err();
+        assert_eq!(2, err.line);
+        assert_eq!("invalid UTF-8 at byte 4", err.reason);
+    }
+
+    #[test]
+    fn examples() {
+        let mut failures = vec![];
+        for plugin in inventory::iter::<Plugin>() {
+            for (n, example) in plugin.examples.iter().enumerate() {
+                for (part_two, expected) in [(false, example.one), (true, example.two)] {
+                    let Some(expected) = expected else { continue };
+                    let actual = plugin.parse_bytes(example.input.as_bytes())
+                        .and_then(|input| plugin.solve(&input, part_two))
+                        .map_or_else(|e| format!("{:#}", e), |answer| answer.to_string());
+                    if actual != expected {
+                        failures.push(format!("{} example {} part {}: expected {:?}, got {:?}",
+                                              plugin.name, n + 1, if part_two { 2 } else { 1 }, expected, actual));
+                    }
+                }
+            }
+        }
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
@@ -1,7 +1,7 @@
 use std::str::{from_utf8, FromStr};
 
 pub use pom::parser::{self, one_of, Parser};
-use pom::parser::{end, list};
+use pom::parser::{end, list, sym};
 
 pub fn space<'a>() -> Parser<'a, u8, ()> {
     one_of(b" \t").repeat(0..).discard()
@@ -16,6 +16,12 @@ pub fn integer<'a>() -> Parser<'a, u8, i32> {
     integer.collect().convert(from_utf8).convert(|s| i32::from_str(&s))
 }
 
+/// An integer with an optional minus sign.
+pub fn signed<'a>() -> Parser<'a, u8, i32> {
+    let integer = sym(b'-').opt() - one_of(b"0123456789") - one_of(b"0123456789").repeat(0..);
+    integer.collect().convert(from_utf8).convert(i32::from_str)
+}
+
 fn itu() -> Parser<'static, u8, (i32, i32)> {
     let parser = (integer() - space()).repeat(2) - end();
     parser.map(|v| (v[0], v[1]))
@@ -33,3 +39,10 @@ fn two_numbers() {
     let output = parser.parse(b"11 22");
     assert_eq!(output, Ok(vec![11, 22]));
 }
+
+#[test]
+fn negative() {
+    let parser = list(signed(), sym(b',')) - end();
+    assert_eq!(Ok(vec![-2, 15, 0]), parser.parse(b"-2,15,0"));
+    assert!(signed().parse(b"-").is_err());
+}
@@ -0,0 +1,66 @@
+/// A maximisation for [maximize]: states lead to other states, each one worth something as it is
+/// and with an upper bound on what any state reached from it can be worth.
+pub trait Problem {
+    type State;
+    type Value: Ord + Copy;
+
+    /// What the search gets if it stops at `state`.
+    fn value(&self, state: &Self::State) -> Self::Value;
+
+    /// Nothing reached from `state` is worth more than this. The closer to the truth, the more of
+    /// the search is cut off, but it must never be less.
+    fn bound(&self, state: &Self::State) -> Self::Value;
+
+    /// Adds the states `state` leads to, the one pushed last is explored first.
+    fn branch(&self, state: &Self::State, next: &mut Vec<Self::State>);
+}
+
+/// Depth first branch and bound from `start`, skipping every state whose bound can't beat the
+/// best value seen so far. Returns that best value.
+pub fn maximize<P: Problem>(problem: &P, start: P::State) -> P::Value {
+    let mut best = problem.value(&start);
+    let mut stack = vec![start];
+    while let Some(state) = stack.pop() {
+        best = best.max(problem.value(&state));
+        if problem.bound(&state) > best {
+            problem.branch(&state, &mut stack);
+        }
+    }
+    best
+}
+
+/// 0-1 knapsack of `(weight, value)` items, a state is the next item to decide on with the
+/// weight and value packed so far.
+#[cfg(test)]
+struct Knapsack {
+    items: Vec<(u32, u32)>,
+    capacity: u32,
+}
+
+#[cfg(test)]
+impl Problem for Knapsack {
+    type State = (usize, u32, u32);
+    type Value = u32;
+
+    fn value(&self, &(_, _, value): &Self::State) -> u32 {
+        value
+    }
+
+    fn bound(&self, &(n, _, value): &Self::State) -> u32 {
+        value + self.items[n..].iter().map(|&(_, v)| v).sum::<u32>()
+    }
+
+    fn branch(&self, &(n, weight, value): &Self::State, next: &mut Vec<Self::State>) {
+        let Some(&(w, v)) = self.items.get(n) else { return };
+        next.push((n + 1, weight, value));
+        if weight + w <= self.capacity {
+            next.push((n + 1, weight + w, value + v));
+        }
+    }
+}
+
+#[test]
+fn knapsack() {
+    let knapsack = Knapsack { items: vec![(5, 10), (4, 40), (6, 30), (3, 50)], capacity: 10 };
+    assert_eq!(90, maximize(&knapsack, (0, 0, 0)));
+}
@@ -11,10 +11,16 @@ pub mod s09;
 pub mod s10;
 pub mod s11;
 pub mod s12;
+pub mod s13;
 pub mod s14;
+pub mod s15;
+pub mod s16;
 pub mod s17;
 pub mod s18;
+pub mod s19;
+pub mod s20;
+pub mod s21;
+pub mod s22;
 pub mod s23;
 pub mod s24;
-pub mod s22;
-pub mod s21;
+pub mod s25;
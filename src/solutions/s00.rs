@@ -1,9 +1,29 @@
-use crate::{InputIterator, Ztr};
+use anyhow::Result;
 
-pub fn solution(_: InputIterator, part_two: bool) -> Ztr {
-    if part_two {
+use crate::{Example, InputIterator, Solution};
+
+static _TEST_DATA: &str = "";
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = ();
+    type One = usize;
+    type Two = usize;
+
+    const TITLE: Option<&'static str> = None;
+    const IMPLEMENTED: [bool; 2] = [false, false];
+    const EXAMPLES: &'static [Example] = &[Example { input: _TEST_DATA, one: None, two: None }];
+
+    fn parse(_: InputIterator) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn part_one(_: &Self::Input) -> Result<Self::One> {
         todo!()
-    } else {
+    }
+
+    fn part_two(_: &Self::Input) -> Result<Self::Two> {
         todo!()
     }
 }
@@ -11,8 +31,6 @@ pub fn solution(_: InputIterator, part_two: bool) -> Ztr {
 #[cfg(test)]
 mod tests {
 
-    static _TEST_DATA: &str = "";
-
     #[test]
     fn test1() {
         assert!(true);
@@ -1,6 +1,8 @@
 use std::cmp::Reverse;
 
-use crate::{InputIterator, Ztr};
+use anyhow::Result;
+
+use crate::{Example, InputError, InputIterator, Solution};
 
 static _TEST_DATA: &str = "1000
 2000
@@ -18,35 +20,58 @@ static _TEST_DATA: &str = "1000
 10000
 ";
 
-fn helper(i: InputIterator) -> Box<Vec<usize>> {
+fn helper(i: InputIterator) -> Result<Box<Vec<usize>>> {
     let mut acc = Box::new(Vec::with_capacity(16));
     acc.push(0);
-    for r in i.map(|l| l.parse::<usize>()) {
-        match r {
-            Ok(num) => {
-                if let Some(last) = acc.pop() {
-                    acc.push(last + num)
-                }
-            }
-            _ => acc.push(0)
+    for (n, line) in i.enumerate() {
+        if line.is_empty() {
+            acc.push(0);
+            continue
+        }
+        let num = line.parse::<usize>().map_err(|e| InputError::new(n, &line, e.to_string()))?;
+        if let Some(last) = acc.pop() {
+            acc.push(last + num)
         }
     }
     acc.sort_by_key(|w| Reverse(*w));
-    acc
+    Ok(acc)
 }
 
-pub fn solution(i: InputIterator, part_two: bool) -> Ztr {
-    let sorted = helper(i);
-    let wut = if part_two { &sorted[0..3] } else { &sorted[0..1] };
-    wut.iter().sum::<usize>().to_string().into()
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Box<Vec<usize>>;
+    type One = usize;
+    type Two = usize;
+
+    const TITLE: Option<&'static str> = Some("Calorie Counting");
+    const EXAMPLES: &'static [Example] = &[Example { input: _TEST_DATA, one: Some("24000"), two: Some("45000") }];
+
+    fn parse(i: InputIterator) -> Result<Self::Input> {
+        helper(i)
+    }
+
+    fn part_one(sorted: &Self::Input) -> Result<Self::One> {
+        Ok(sorted[0..1].iter().sum())
+    }
+
+    fn part_two(sorted: &Self::Input) -> Result<Self::Two> {
+        Ok(sorted[0..3].iter().sum())
+    }
 }
 
 #[test]
 fn test1() {
-    assert_eq!(solution(&mut (_TEST_DATA.lines().map(|s| s.into())), false), "24000");
+    assert_eq!(Day::part_one(&Day::parse(&mut (_TEST_DATA.lines().map(|s| s.into()))).unwrap()).unwrap(), 24000);
 }
 
 #[test]
 fn test2() {
-    assert_eq!(solution(&mut (_TEST_DATA.lines().map(|s| s.into())), true), "45000");
+    assert_eq!(Day::part_two(&Day::parse(&mut (_TEST_DATA.lines().map(|s| s.into()))).unwrap()).unwrap(), 45000);
+}
+
+#[test]
+fn bad_calories() {
+    let err = helper(&mut "1000\n2o00".lines().map(|s| s.into())).unwrap_err();
+    assert_eq!(2, err.downcast_ref::<InputError>().unwrap().line);
 }
@@ -1,10 +1,11 @@
 use std::cmp::Ordering::{self, *};
 
+use anyhow::Result;
 use strum_macros::EnumString;
 
 use RPS::*;
 
-use crate::{InputIterator, Ztr};
+use crate::{Example, InputError, InputIterator, Solution, Ztr};
 
 static _TEST_DATA: &str = "A Y
 B X
@@ -88,12 +89,33 @@ fn p1(i: InputIterator) -> u32 {
 }
 
 
-pub fn solution(i: InputIterator, part_two: bool) -> Ztr {
-    (if part_two {
-        combine(i, move_for_line)
-    } else {
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Ztr>;
+    type One = u32;
+    type Two = u32;
+
+    const TITLE: Option<&'static str> = Some("Rock Paper Scissors");
+    const IMPLEMENTED: [bool; 2] = [false, true];
+    const EXAMPLES: &'static [Example] = &[Example { input: _TEST_DATA, one: None, two: Some("12") }];
+
+    fn parse(i: InputIterator) -> Result<Self::Input> {
+        i.enumerate()
+         .map(|(n, line)| match line.as_bytes() {
+             [b'A'..=b'C', b' ', b'X'..=b'Z'] => Ok(line),
+             _ => Err(InputError::new(n, &line, "expected a round like \"A Y\"").into())
+         })
+         .collect()
+    }
+
+    fn part_one(_: &Self::Input) -> Result<Self::One> {
         todo!()
-    }).to_string().into()
+    }
+
+    fn part_two(lines: &Self::Input) -> Result<Self::Two> {
+        Ok(combine(&mut lines.iter().cloned(), move_for_line))
+    }
 }
 
 #[cfg(test)]
@@ -137,6 +159,11 @@ mod test {
         assert_eq!(7, move_for_line("C Z"));
     }
 
+    #[test]
+    fn bad_round() {
+        assert!(Day::parse(&mut ["A Y", "B W"].into_iter().map(|l| l.into())).is_err());
+    }
+
     #[test]
     fn test2() {
         assert_eq!(12, combine(&mut (_TEST_DATA.lines().map(|l| l.into())), move_for_line));
@@ -1,6 +1,7 @@
+use anyhow::Result;
 use itertools::{Itertools, izip};
 
-use crate::{InputIterator, Ztr};
+use crate::{Example, InputError, InputIterator, Solution, Ztr};
 
 static _TEST_DATA: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
 jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
@@ -71,12 +72,33 @@ fn f2(i: InputIterator) -> i32 {
         .sum()
 }
 
-pub fn solution(i: InputIterator, part_two: bool) -> Ztr {
-    (if part_two {
-        f2(i)
-    } else {
-        f1(i)
-    }).to_string().into()
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Ztr>;
+    type One = i32;
+    type Two = i32;
+
+    const TITLE: Option<&'static str> = Some("Rucksack Reorganization");
+    const EXAMPLES: &'static [Example] = &[Example { input: _TEST_DATA, one: Some("157"), two: Some("70") }];
+
+    fn parse(i: InputIterator) -> Result<Self::Input> {
+        i.enumerate()
+         .map(|(n, line)| match line {
+             l if !l.chars().all(|c| c.is_ascii_alphabetic()) => Err(InputError::new(n, &l, "items must be letters").into()),
+             l if l.len() % 2 != 0 => Err(InputError::new(n, &l, "compartments differ in size").into()),
+             l => Ok(l)
+         })
+         .collect()
+    }
+
+    fn part_one(lines: &Self::Input) -> Result<Self::One> {
+        Ok(f1(&mut lines.iter().cloned()))
+    }
+
+    fn part_two(lines: &Self::Input) -> Result<Self::Two> {
+        Ok(f2(&mut lines.iter().cloned()))
+    }
 }
 
 #[test]
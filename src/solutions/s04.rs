@@ -1,6 +1,7 @@
-use pom::parser::{Parser, sym};
+use anyhow::Result;
+use pom::parser::{end, Parser, sym};
 
-use crate::{InputIterator, Ztr};
+use crate::{byte_lines, Example, InputError, InputIterator, Solution};
 use crate::parsers::integer;
 
 static _TEST_DATA: &str = "2-4,6-8
@@ -14,17 +15,20 @@ fn range<'a>() -> Parser<'a, u8, (i32, i32)> {
     (integer() - sym(b'-').discard()) + integer()
 }
 
-fn parse_line(line: &str) -> ((i32, i32), (i32, i32)) {
-    let parser = range() - sym(b',').discard() + range();
-    parser.parse(line.as_bytes()).unwrap_or_default()
+type Pair = ((i32, i32), (i32, i32));
+
+fn parse_line(n: usize, line: &[u8]) -> Result<Pair, InputError> {
+    let parser = range() - sym(b',').discard() + range() - end();
+    parser.parse(line).map_err(|e| InputError::new(n, &String::from_utf8_lossy(line), e.to_string()))
 }
 
 #[test]
 fn real_input_parses() {
-    assert_eq!(((85, 97), (86, 99)), parse_line("85-97,86-99"));
+    assert_eq!(((85, 97), (86, 99)), parse_line(0, b"85-97,86-99").unwrap());
+    assert_eq!(4, parse_line(3, b"85-97,86").unwrap_err().line);
 }
 
-fn is_within(&((fs, fe), (ss, se)): &((i32, i32), (i32, i32))) -> bool {
+fn is_within(&((fs, fe), (ss, se)): &Pair) -> bool {
     (fs <= ss && fe >= se) || (ss <= fs && se >= fe)
 }
 
@@ -35,32 +39,58 @@ fn complete_overlap_works() {
     assert!(!is_within(&((2, 4), (6, 8))));
 }
 
-fn is_overlap(&((fs, fe), (ss, se)): &((i32, i32), (i32, i32))) -> bool {
+fn is_overlap(&((fs, fe), (ss, se)): &Pair) -> bool {
     (fe >= ss && fe <= se) || (se >= fs && se <= fe)
 }
 
-fn f1 (i: InputIterator) -> usize {
-    i.map(|l| parse_line(&l)).filter(is_within).count()
+fn f1 (pairs: &[Pair]) -> usize {
+    pairs.iter().filter(|p| is_within(p)).count()
 }
 
-fn f2(i: InputIterator) -> usize {
-    i.map(|l| parse_line(l.as_ref())).filter(is_overlap).count()
+fn f2(pairs: &[Pair]) -> usize {
+    pairs.iter().filter(|p| is_overlap(p)).count()
 }
 
-pub fn solution(i: InputIterator, part_two: bool) -> Ztr {
-    (if part_two {
-        f2(i)
-    } else {
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Pair>;
+    type One = usize;
+    type Two = usize;
+
+    const TITLE: Option<&'static str> = Some("Camp Cleanup");
+    const IMPLEMENTED: [bool; 2] = [false, true];
+    const EXAMPLES: &'static [Example] = &[Example { input: _TEST_DATA, one: None, two: Some("4") }];
+
+    fn parse(i: InputIterator) -> Result<Self::Input> {
+        Ok(i.enumerate().map(|(n, l)| parse_line(n, l.as_bytes())).collect::<Result<_, _>>()?)
+    }
+
+    fn parse_bytes(bytes: &[u8]) -> Result<Self::Input> {
+        Ok(byte_lines(bytes).enumerate().map(|(n, l)| parse_line(n, l)).collect::<Result<_, _>>()?)
+    }
+
+    fn part_one(_: &Self::Input) -> Result<Self::One> {
         todo!()
-    }).to_string().into()
+    }
+
+    fn part_two(pairs: &Self::Input) -> Result<Self::Two> {
+        Ok(f2(pairs))
+    }
 }
 
 #[test]
 fn test1() {
-    assert_eq!(2, f1(& mut (_TEST_DATA.lines().map(|s| s.into()))));
+    assert_eq!(2, f1(&Day::parse(& mut (_TEST_DATA.lines().map(|s| s.into()))).unwrap()));
+}
+
+#[test]
+fn from_bytes() {
+    assert_eq!(Day::parse_bytes(_TEST_DATA.as_bytes()).unwrap(), Day::parse(& mut (_TEST_DATA.lines().map(|s| s.into()))).unwrap());
+    assert_eq!(2, Day::parse_bytes(b"2-4,6-8\r\n2-3,\xff").unwrap_err().downcast_ref::<InputError>().unwrap().line);
 }
 
 #[test]
 fn test2() {
-    assert_eq!(4, f2(& mut (_TEST_DATA.lines().map(|s| s.into()))));
+    assert_eq!(4, f2(&Day::parse(& mut (_TEST_DATA.lines().map(|s| s.into()))).unwrap()));
 }
@@ -1,6 +1,7 @@
-use pom::parser::seq;
+use anyhow::{ensure, Result};
+use pom::parser::{end, seq};
 
-use crate::{InputIterator, Ztr};
+use crate::{Example, InputError, InputIterator, Solution};
 use crate::parsers::{integer, space};
 
 static _TEST_DATA: &str = "    [D]
@@ -14,17 +15,18 @@ move 2 from 2 to 1
 move 1 from 1 to 2
 ";
 
-fn parse_move(line: &str) -> (i32, i32, i32) {
+fn parse_move(n: usize, line: &str) -> Result<(i32, i32, i32), InputError> {
     let parser = (seq(b"move").discard() + space()) * integer() +
                  (space() + seq(b"from").discard() + space()) * integer() +
-                 (space() + seq(b"to").discard() + space()) * integer();
-    let parsed = parser.parse(line.as_bytes()).unwrap_or_default();
-    (parsed.0.0, parsed.0.1, parsed.1)
+                 (space() + seq(b"to").discard() + space()) * integer() - end();
+    let parsed = parser.parse(line.as_bytes()).map_err(|e| InputError::new(n, line, e.to_string()))?;
+    Ok((parsed.0.0, parsed.0.1, parsed.1))
 }
 
 #[test]
 fn move_parsed_correctly() {
-    assert_eq!((2, 2, 7), parse_move("move 2 from 2 to 7"));
+    assert_eq!((2, 2, 7), parse_move(0, "move 2 from 2 to 7").unwrap());
+    assert!(parse_move(0, "move 2 from 2 to").is_err());
 }
 
 fn parse_crates(line: &str) -> Vec<(usize, char)> {
@@ -45,22 +47,31 @@ fn add_to_stacks(stacks: & mut Vec<Vec<char>>, krates: &str) {
     })
 }
 
-fn parse(i: InputIterator) -> (Vec<Vec<char>>, Vec<(i32, i32, i32)>) {
+type Cargo = (Vec<Vec<char>>, Vec<(i32, i32, i32)>);
+
+fn parse(i: InputIterator) -> Result<Cargo, InputError> {
     let mut stacks: Vec<Vec<char>> = Vec::with_capacity(16);
     let mut instructions: Vec<(i32, i32, i32)> = Vec::new();
-    for line in i {
+    for (n, line) in i.enumerate() {
         match &line {
             krates if krates.contains("[") => add_to_stacks(& mut stacks, krates),
-            instr if line.contains("move") => instructions.push(parse_move(instr)),
+            instr if line.contains("move") => {
+                let (num, from, to) = parse_move(n, instr)?;
+                let valid = 1..=stacks.len() as i32;
+                if !valid.contains(&from) || !valid.contains(&to) {
+                    return Err(InputError::new(n, instr, format!("there are only {} stacks", stacks.len())))
+                }
+                instructions.push((num, from, to))
+            }
             _ => ()
         }
     };
-    (stacks, instructions)
+    Ok((stacks, instructions))
 }
 
 #[test]
 fn partial_read() {
-    let (mut stacks, mut instructions) = parse(& mut (_TEST_DATA.lines().map(|s| s.into())));
+    let (mut stacks, mut instructions) = parse(& mut (_TEST_DATA.lines().map(|s| s.into()))).unwrap();
     assert_eq!(Some('N'), stacks[0].pop());
     assert_eq!(Some('D'), stacks[1].pop());
     assert_eq!(Some((1, 1, 2)), instructions.pop());
@@ -83,30 +94,47 @@ fn move_9000(stacks: & mut Vec<Vec<char>>, (mut num, from, to): (i32, i32, i32))
 
 type Mover = fn(& mut Vec<Vec<char>>, (i32, i32, i32));
 
-fn f1(i: InputIterator, mover: Mover) -> String {
-    let (mut stacks, instructions) = parse(i);
-    instructions.iter()
-                .for_each(|&(num, from, to)| mover(& mut stacks, (num, from - 1, to - 1)));
-    stacks.iter()
-          .map(|stack| stack.last())
-          .flat_map(|opt| opt)
-          .collect()
+fn f1((stacks, instructions): &Cargo, mover: Mover) -> Result<String> {
+    let mut stacks = stacks.clone();
+    for &(num, from, to) in instructions {
+        let available = stacks[from as usize - 1].len();
+        ensure!(num as usize <= available, "move {} from {} to {}: only {} crates there", num, from, to, available);
+        mover(& mut stacks, (num, from - 1, to - 1));
+    }
+    Ok(stacks.iter()
+             .map(|stack| stack.last())
+             .flat_map(|opt| opt)
+             .collect())
 }
 
 #[test]
 fn test_move() {
-    let (mut stacks, instructions) = parse(& mut (_TEST_DATA.lines().map(|s| s.into())));
+    let (mut stacks, instructions) = parse(& mut (_TEST_DATA.lines().map(|s| s.into()))).unwrap();
     instructions.iter().for_each(|&(num, from, to)| move_9001(& mut stacks, (num, from - 1, to - 1)));
     assert_eq!(Some('M'), stacks[0].pop());
     assert_eq!(Some('C'), stacks[1].pop());
     assert_eq!(Some('D'), stacks[2].pop());
 }
 
-pub fn solution(i: InputIterator, part_two: bool) -> Ztr {
-    let mover = if part_two {
-        move_9001
-    } else {
-        move_9000
-    };
-    f1(i, mover).into()
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Cargo;
+    type One = String;
+    type Two = String;
+
+    const TITLE: Option<&'static str> = Some("Supply Stacks");
+    const EXAMPLES: &'static [Example] = &[Example { input: _TEST_DATA, one: Some("CMZ"), two: Some("MCD") }];
+
+    fn parse(i: InputIterator) -> Result<Self::Input> {
+        Ok(parse(i)?)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::One> {
+        f1(input, move_9000)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Two> {
+        f1(input, move_9001)
+    }
 }
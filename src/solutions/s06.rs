@@ -1,12 +1,13 @@
 use std::collections::VecDeque;
 
+use anyhow::{Context, Result};
 use itertools::Itertools;
 
-use crate::{InputIterator, Ztr};
+use crate::{Example, InputIterator, Solution, Ztr};
 
 static _TEST_DATA: &str = "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw";
 
-fn find_marker(i: InputIterator, len: usize) -> usize {
+fn find_marker(i: InputIterator, len: usize) -> Option<usize> {
     let mut buf: VecDeque<char> = VecDeque::new();
     i.next()
      .iter()
@@ -25,20 +26,42 @@ fn find_marker(i: InputIterator, len: usize) -> usize {
                          })
                          .map(|(i, _)| i))
      .last()
-     .unwrap_or_default()
 }
 
-pub fn solution(i: InputIterator, part_two: bool) -> Ztr {
-    let n = if part_two { 4 } else { 14 };
-    find_marker(i, n).to_string().into()
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Ztr>;
+    type One = usize;
+    type Two = usize;
+
+    const TITLE: Option<&'static str> = Some("Tuning Trouble");
+    const EXAMPLES: &'static [Example] = &[Example { input: _TEST_DATA, one: Some("11"), two: Some("26") }];
+
+    fn parse(i: InputIterator) -> Result<Self::Input> {
+        Ok(i.collect())
+    }
+
+    fn part_one(lines: &Self::Input) -> Result<Self::One> {
+        find_marker(&mut lines.iter().cloned(), 4).context("no marker in the datastream")
+    }
+
+    fn part_two(lines: &Self::Input) -> Result<Self::Two> {
+        find_marker(&mut lines.iter().cloned(), 14).context("no marker in the datastream")
+    }
 }
 
 #[test]
 fn test1() {
-    assert_eq!(11, find_marker(& mut (_TEST_DATA.lines().map(|s| s.into())), 4));
+    assert_eq!(Some(11), find_marker(& mut (_TEST_DATA.lines().map(|s| s.into())), 4));
 }
 
 #[test]
 fn test2() {
-    assert_eq!(26, find_marker(& mut (_TEST_DATA.lines().map(|s| s.into())), 14));
+    assert_eq!(Some(26), find_marker(& mut (_TEST_DATA.lines().map(|s| s.into())), 14));
+}
+
+#[test]
+fn no_marker() {
+    assert_eq!(None, find_marker(& mut ["abab"].into_iter().map(|s| s.into()), 4));
 }
@@ -1,10 +1,12 @@
 use std::collections::HashMap;
 use std::str::FromStr;
 
+use anyhow::{Context, Result};
 use itertools::Itertools;
+use log::debug;
 use regex::Regex::{self};
 
-use crate::{InputIterator, Ztr};
+use crate::{Example, InputError, InputIterator, Solution, Ztr};
 
 static _TEST_DATA: &str = "$ cd /
 $ ls
@@ -40,35 +42,36 @@ fn update_sizes(path: &[String], sizes: &mut HashMap<String, i32>, size: i32) {
     }
 }
 
-fn modify_path(path: & mut Vec<String>, c: &str, line: usize) {
+fn modify_path(path: & mut Vec<String>, c: &str, line: usize, text: &str) -> Result<(), InputError> {
     match c {
         "/" => {
             path.clear();
         },
         ".." => {
             if path.pop().is_none() {
-                panic!("error traversing. line {}", line)
+                return Err(InputError::new(line, text, "already at the root"))
             }
         },
         p => path.push(p.to_string())
     }
+    Ok(())
 }
 
-fn walk_os_tree(i: InputIterator) -> i32 {
-    let cd = Regex::new(r"cd\s+(?P<name>\S+)").unwrap();
-    let file = Regex::new(r"^(?P<size>\d+)\s+\S+").unwrap();
+fn walk_os_tree(i: InputIterator) -> Result<i32> {
+    let cd = Regex::new(r"cd\s+(?P<name>\S+)")?;
+    let file = Regex::new(r"^(?P<size>\d+)\s+\S+")?;
     let mut path: Vec<String> = vec![];
     let mut sizes: HashMap<String, i32> = HashMap::new();
     let mut size = 0_i32;
     for (line, str) in i.enumerate() {
-        cd.captures(str.as_ref()).iter().for_each(|c| {
+        if let Some(c) = cd.captures(str.as_ref()) {
             update_sizes(&path, &mut sizes, size);
             size = 0;
-            modify_path(& mut path, &c["name"], line);
-        });
-        file.captures(str.as_ref()).iter().for_each(|c| {
-            size += i32::from_str(&c["size"]).unwrap_or_default();
-        });
+            modify_path(& mut path, &c["name"], line, &str)?;
+        }
+        if let Some(c) = file.captures(str.as_ref()) {
+            size += i32::from_str(&c["size"]).map_err(|e| InputError::new(line, &str, e.to_string()))?;
+        }
         if str.starts_with("$ ls") {
             size = 0;
         }
@@ -76,23 +79,38 @@ fn walk_os_tree(i: InputIterator) -> i32 {
     update_sizes(&path, &mut sizes, size);
     let total_size = &sizes.get("").map(|v| *v).unwrap_or_default();
     let to_free = 30000000 - (70000000 - total_size);
-    println!("total size: {}, to free: {}", total_size, to_free);
+    debug!("total size: {}, to free: {}", total_size, to_free);
     //sizes.iter().map(|e| *e.1).filter(|s| *s < 100000).sum()
     sizes.iter()
          .map(|e| *e.1)
          .sorted()
          .skip_while(|s| *s < to_free)
          .next()
-         .unwrap_or_default()
+         .context("no directory is big enough")
 }
 
-pub fn solution(i: InputIterator, part_two: bool) -> Ztr {
-    (if part_two {
-        walk_os_tree(i)
-    } else {
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Ztr>;
+    type One = i32;
+    type Two = i32;
+
+    const TITLE: Option<&'static str> = Some("No Space Left On Device");
+    const IMPLEMENTED: [bool; 2] = [false, true];
+    const EXAMPLES: &'static [Example] = &[Example { input: _TEST_DATA, one: None, two: Some("24933642") }];
+
+    fn parse(i: InputIterator) -> Result<Self::Input> {
+        Ok(i.collect())
+    }
+
+    fn part_one(_: &Self::Input) -> Result<Self::One> {
         todo!()
-    }).to_string().into()
+    }
 
+    fn part_two(lines: &Self::Input) -> Result<Self::Two> {
+        walk_os_tree(&mut lines.iter().cloned())
+    }
 }
 
 #[test]
@@ -109,5 +127,11 @@ fn test1() {
 
 #[test]
 fn test3() {
-    assert_eq!(24933642, walk_os_tree(& mut (_TEST_DATA.lines().map(|s| s.into()))));
+    assert_eq!(24933642, walk_os_tree(& mut (_TEST_DATA.lines().map(|s| s.into()))).unwrap());
+}
+
+#[test]
+fn above_root() {
+    let err = walk_os_tree(& mut ["$ cd /", "$ cd .."].into_iter().map(|s| s.into())).unwrap_err();
+    assert_eq!(2, err.downcast_ref::<InputError>().unwrap().line);
 }
@@ -1,8 +1,9 @@
 use std::collections::HashSet;
 
+use anyhow::{ensure, Result};
 use itertools::{Either, Itertools};
 
-use crate::{InputIterator, Ztr};
+use crate::{Example, InputError, InputIterator, Solution, Ztr};
 
 static _TEST_DATA: &str = "30373
 25512
@@ -70,29 +71,54 @@ fn score(row: usize, col: usize, patch: &[&[i32]]) -> i32 {
     partial_scores.iter().product()
 }
 
-pub fn solution(it: InputIterator, part_two: bool) -> Ztr {
-    let mut buf: Vec<i32> = Vec::with_capacity(10000);
-    let patch_vec = read_to_array(it, &mut buf);
-    let patch = patch_vec.as_slice();
-    let mut visible: HashSet<(usize, usize)> = HashSet::new();
-    let w = patch[0].len();
-    let h = patch.len();
-    let res = if part_two {
-        (0..h).cartesian_product(0..w)
-            .map(|(r, c)| score(r, c, patch))
-            .max()
-            .unwrap_or_default()
-            .to_string()
-    } else {
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<i32>, usize);
+    type One = usize;
+    type Two = i32;
+
+    const TITLE: Option<&'static str> = Some("Treetop Tree House");
+    const EXAMPLES: &'static [Example] = &[Example { input: _TEST_DATA, one: Some("21"), two: Some("8") }];
+
+    fn parse(it: InputIterator) -> Result<Self::Input> {
+        let mut lines: Vec<Ztr> = Vec::with_capacity(100);
+        for (n, line) in it.enumerate() {
+            if !line.chars().all(|c| c.is_ascii_digit()) {
+                return Err(InputError::new(n, &line, "tree heights must be digits").into())
+            }
+            if let Some(first) = lines.first().map(|l| l.len()).filter(|&w| w != line.len()) {
+                return Err(InputError::new(n, &line, format!("expected {} trees", first)).into())
+            }
+            lines.push(line);
+        }
+        ensure!(lines.first().is_some_and(|l| !l.is_empty()), "no trees in the input");
+        let mut buf: Vec<i32> = Vec::with_capacity(10000);
+        let w = read_to_array(&mut lines.into_iter(), &mut buf).first().map(|row| row.len()).unwrap_or_default();
+        Ok((buf, w))
+    }
+
+    fn part_one((buf, w): &Self::Input) -> Result<Self::One> {
+        let patch_vec = buf.chunks(*w).collect::<Vec<_>>();
+        let patch = patch_vec.as_slice();
+        let mut visible: HashSet<(usize, usize)> = HashSet::new();
         let hr = w - 1;
-        let vr = h - 1;
+        let vr = patch.len() - 1;
         visibility_check(0, hr, 0, vr, false, patch, &mut visible);
         visibility_check(0, hr, 0, vr, true, patch, &mut visible);
         visibility_check(hr, 0, vr, 0, true, patch, &mut visible);
         visibility_check(hr, 0, vr, 0, false, patch, &mut visible);
-        visible.len().to_string()
-    };
-    res.into()
+        Ok(visible.len())
+    }
+
+    fn part_two((buf, w): &Self::Input) -> Result<Self::Two> {
+        let patch_vec = buf.chunks(*w).collect::<Vec<_>>();
+        let patch = patch_vec.as_slice();
+        Ok((0..patch.len()).cartesian_product(0..*w)
+            .map(|(r, c)| score(r, c, patch))
+            .max()
+            .unwrap_or_default())
+    }
 }
 
 #[test]
@@ -111,7 +137,7 @@ fn test1() {
     visibility_check(0, hr, 0, vr, true, patch, &mut visible);
     visibility_check(hr, 0, vr, 0, true, patch, &mut visible);
     visibility_check(hr, 0, vr, 0, false, patch, &mut visible);
-    //println!("{:?}", &visible);
+    log::debug!("{:?}", &visible);
     assert_eq!(21, visible.len());
     let w = patch[0].len();
     let h = patch.len();
@@ -128,3 +154,8 @@ fn test1() {
 fn test2() {
     assert!(true);
 }
+
+#[test]
+fn ragged() {
+    assert!(Day::parse(& mut ["303", "25"].into_iter().map(|s| s.into())).is_err());
+}
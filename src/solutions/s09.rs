@@ -3,7 +3,10 @@ use std::collections::HashSet;
 use std::iter::repeat;
 use std::str::FromStr;
 
-use crate::{InputIterator, Ztr};
+use anyhow::Result;
+use log::trace;
+
+use crate::{Example, InputError, InputIterator, Solution};
 
 static _TEST_DATA: &str = "R 4
 U 4
@@ -42,7 +45,7 @@ fn mv(&(r, c): &(Coord, Coord), dir: char) -> (Coord, Coord) {
         '↘' => (r - 1, c + 1),
         '↙' => (r - 1, c - 1),
         '↖' => (r + 1, c - 1),
-        _ => panic!("unhadled direction {}", dir)
+        _ => unreachable!("unhandled direction {}, parse lets only RLUD through", dir)
     }
 }
 
@@ -82,47 +85,71 @@ impl State {
 
 }
 
-fn read_input(it: InputIterator) -> impl Iterator<Item = char> + '_ {
-    it.flat_map(|line| {
-        let mut components = line.split_whitespace().collect::<Vec<_>>();
-        let count = components.pop().and_then(|num| usize::from_str(num).ok()).unwrap_or_default();
-        repeat(components[0].chars().next().unwrap()).take(count)
-    })
+fn read_input(it: InputIterator) -> Result<Vec<char>, InputError> {
+    let mut dirs = Vec::new();
+    for (n, line) in it.enumerate() {
+        let (dir, count) = match line.split_whitespace().collect::<Vec<_>>()[..] {
+            [dir @ ("R" | "L" | "U" | "D"), count] => (dir, count),
+            _ => return Err(InputError::new(n, &line, "expected a direction (RLUD) and a count"))
+        };
+        let count = usize::from_str(count).map_err(|e| InputError::new(n, &line, e.to_string()))?;
+        dirs.extend(repeat(dir.chars().next().unwrap_or_default()).take(count));
+    }
+    Ok(dirs)
 }
 
 #[test]
 fn test_read() {
-    let read = read_input(& mut (_TEST_DATA.lines().map(|s| s.into()))).collect::<Vec<_>>();
-    println!("{:?}", read)
+    let read = read_input(& mut (_TEST_DATA.lines().map(|s| s.into()))).unwrap();
+    println!("{:?}", read);
+    assert_eq!(3, read_input(& mut ["R 4", "U 4", "X 3"].into_iter().map(|s| s.into())).unwrap_err().line);
 }
 
-pub fn solution(it: InputIterator, part_two: bool) -> Ztr {
+fn tail_visits(dirs: &[char], len: usize) -> usize {
     let mut trace: HashSet<(Coord, Coord)> = HashSet::new();
-    let len = if part_two {
-        10
-    } else {
-        2
-    };
     let mut state = State::new(len);
-    read_input(it).enumerate().for_each(|(_n, dir)| {
+    dirs.iter().enumerate().for_each(|(n, &dir)| {
         state.step(dir);
         trace.insert(*state.tail());
-        //println!("{:03} {} {:?}", _n, dir, &state.rope);
+        trace!("{:03} {} {:?}", n, dir, &state.rope);
     });
-    trace.len().to_string().into()
+    trace.len()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<char>;
+    type One = usize;
+    type Two = usize;
+
+    const TITLE: Option<&'static str> = Some("Rope Bridge");
+    const EXAMPLES: &'static [Example] = &[Example { input: _TEST_DATA, one: Some("13"), two: Some("1") }];
+
+    fn parse(it: InputIterator) -> Result<Self::Input> {
+        Ok(read_input(it)?)
+    }
+
+    fn part_one(dirs: &Self::Input) -> Result<Self::One> {
+        Ok(tail_visits(dirs, 2))
+    }
+
+    fn part_two(dirs: &Self::Input) -> Result<Self::Two> {
+        Ok(tail_visits(dirs, 10))
+    }
 }
 
 #[test]
 fn test1() {
-    assert_eq!("13", solution(& mut (_TEST_DATA.lines().map(|s| s.into())), false));
+    assert_eq!(13, Day::part_one(&Day::parse(& mut (_TEST_DATA.lines().map(|s| s.into()))).unwrap()).unwrap());
 }
 
 #[test]
 fn test21() {
-    assert_eq!("1", solution(& mut (_TEST_DATA.lines().map(|s| s.into())), true));
+    assert_eq!(1, Day::part_two(&Day::parse(& mut (_TEST_DATA.lines().map(|s| s.into()))).unwrap()).unwrap());
 }
 
 #[test]
 fn test22() {
-    assert_eq!("36", solution(& mut (_TEST_DATA2.lines().map(|s| s.into())), true));
+    assert_eq!(36, Day::part_two(&Day::parse(& mut (_TEST_DATA2.lines().map(|s| s.into()))).unwrap()).unwrap());
 }
@@ -1,9 +1,11 @@
 use std::iter::once;
 use std::str::FromStr;
 
+use anyhow::Result;
 use itertools::Either;
+use log::trace;
 
-use crate::{InputIterator, Ztr};
+use crate::{Answer, Example, InputError, InputIterator, Solution};
 
 static _TEST_DATA: &str = "noop
 addx 3
@@ -158,22 +160,32 @@ noop
 noop
 ";
 
-fn read_input(it: InputIterator) -> impl Iterator<Item = i32> + '_ {
-    it.flat_map(|line| {
-        if line.starts_with("noop") {
-            Either::Left(once(0))
-        } else {
-            let mut components = line.split_whitespace().collect::<Vec<_>>();
-            let count = components.pop().and_then(|num| i32::from_str(num).ok()).unwrap_or_default();
-            Either::Right(once(0).chain(once(count)))
-        }
-    })
+static _TEST_PICTURE: &str = "##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+
+fn read_input(it: InputIterator) -> Result<Vec<i32>, InputError> {
+    let mut incs = Vec::new();
+    for (n, line) in it.enumerate() {
+        incs.extend(match line.split_whitespace().collect::<Vec<_>>()[..] {
+            ["noop"] => Either::Left(once(0)),
+            ["addx", count] => {
+                let count = i32::from_str(count).map_err(|e| InputError::new(n, &line, e.to_string()))?;
+                Either::Right(once(0).chain(once(count)))
+            }
+            _ => return Err(InputError::new(n, &line, "expected noop or addx"))
+        })
+    }
+    Ok(incs)
 }
 
-fn one(it: InputIterator, seqs: &[usize]) -> (i32, i32) {
+fn one(incs: &[i32], seqs: &[usize]) -> (i32, i32) {
     let mut acc = 1;
     let mut res = 0;
-    read_input(it)
+    incs.iter()
         .enumerate()
         .for_each(|(n, inc)| {
             let i = n + 1;
@@ -181,51 +193,76 @@ fn one(it: InputIterator, seqs: &[usize]) -> (i32, i32) {
                 res += (i as i32) * acc;
             }
             acc += inc;
-            //println!("{:03} {:03} {:03} {:03}", i, inc, acc, res);
+            trace!("{:03} {:03} {:03} {:03}", i, inc, acc, res);
         });
     (acc, res)
 }
 
-fn two(it: InputIterator) -> i32 {
+fn two(incs: &[i32]) -> (i32, Vec<String>) {
     let mut acc = 1_i32;
-    read_input(it)
+    let mut screen = vec![String::with_capacity(40); incs.len().div_ceil(40)];
+    incs.iter()
         .enumerate()
         .for_each(|(n, inc)| {
-            let i = ((n + 1) % 40) as i32;
-            if i >= acc && i < acc + 3 {
-                print!("{}", '#')
-            } else {
-                print!("{}", '.')
-            }
-            if i % 40 == 0 {
-                println!()
-            }
+            let pixel = (n % 40) as i32;
+            screen[n / 40].push(if (pixel - acc).abs() <= 1 { '#' } else { '.' });
             acc += inc;
         });
-    acc
+    (acc, screen)
 }
 
-pub fn solution(it: InputIterator, part_two: bool) -> Ztr {
-    (if part_two {
-        two(it);
-        0
-    } else {
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<i32>;
+    type One = i32;
+    type Two = Answer;
+
+    const TITLE: Option<&'static str> = Some("Cathode-Ray Tube");
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: _TEST_DATA, one: Some("0"), two: None },
+        Example { input: _TEST_DATA2, one: Some("13140"), two: Some(_TEST_PICTURE) },
+    ];
+
+    fn parse(it: InputIterator) -> Result<Self::Input> {
+        Ok(read_input(it)?)
+    }
+
+    fn part_one(incs: &Self::Input) -> Result<Self::One> {
         let seqs = vec![20, 60, 100, 140, 180, 220];
-        one(it, &seqs).1
-    }).to_string().into()
+        Ok(one(incs, &seqs).1)
+    }
+
+    fn part_two(incs: &Self::Input) -> Result<Self::Two> {
+        Ok(Answer::Grid(two(incs).1))
+    }
 }
 
 #[test]
 fn test1() {
-    assert_eq!(-1, one(& mut (_TEST_DATA.lines().map(|s| s.into())), &vec![]).0)
+    assert_eq!(-1, one(&Day::parse(& mut (_TEST_DATA.lines().map(|s| s.into()))).unwrap(), &vec![]).0)
 }
 
 #[test]
 fn test15() {
-    assert_eq!("13140", solution(& mut (_TEST_DATA2.lines().map(|s| s.into())), false))
+    assert_eq!(13140, Day::part_one(&Day::parse(& mut (_TEST_DATA2.lines().map(|s| s.into()))).unwrap()).unwrap())
 }
 
 #[test]
 fn test2() {
-    assert_eq!(17, two(& mut (_TEST_DATA2.lines().map(|s| s.into()))));
+    let (acc, screen) = two(&Day::parse(& mut (_TEST_DATA2.lines().map(|s| s.into()))).unwrap());
+    assert_eq!(17, acc);
+    assert_eq!(vec![
+        "##..##..##..##..##..##..##..##..##..##..",
+        "###...###...###...###...###...###...###.",
+        "####....####....####....####....####....",
+        "#####.....#####.....#####.....#####.....",
+        "######......######......######......####",
+        "#######.......#######.......#######.....",
+    ], screen);
+}
+
+#[test]
+fn bad_instruction() {
+    assert_eq!(2, read_input(& mut ["noop", "addx"].into_iter().map(|s| s.into())).unwrap_err().line);
 }
@@ -1,9 +1,11 @@
 use std::cell::RefCell;
 use std::fmt::{Display, Formatter};
 
+use anyhow::Result;
 use itertools::{Itertools, izip};
+use log::trace;
 
-use crate::{InputIterator, Ztr};
+use crate::{InputIterator, Solution};
 
 const PRIMES: [u32; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
 
@@ -81,14 +83,14 @@ fn round(monkeys: &mut [Monkey], _: u32) {
     for n in 0..monkeys.len() {
         let m = &mut monkeys[n];
         m.examine().into_iter().for_each(|(dst, item)| {
-            //println!("monkey {} throws {} to {}", n, item, dst);
+            trace!("monkey {} throws {:?} to {}", n, item, dst);
             monkeys[dst].items.borrow_mut().push(item);
         });
     }
 }
 
 
-pub fn solution(_: InputIterator, part_two: bool) -> Ztr {
+fn monkey_business(c: usize, d: u32) -> usize {
     let mut monkeys = vec![
         Monkey::new(&vec![76, 88, 96, 97, 58, 61, 67], rns_mul, 19, 3, (2, 3)),
         Monkey::new(&vec![93, 71, 79, 83, 69, 70, 94, 98], rns_add, 8, 11, (5, 6)),
@@ -99,16 +101,32 @@ pub fn solution(_: InputIterator, part_two: bool) -> Ztr {
         Monkey::new(&vec![62], rns_add, 2, 17, (5, 7)),
         Monkey::new(&vec![85, 54, 53], rns_add, 3, 13, (4, 0)),
     ];
-    let (c, d) = if part_two {
-        (10000, 1)
-    } else {
-        (20, 3)
-    };
     for _ in 0..c {
         round(&mut monkeys, d);
     }
-    let r: usize = monkeys.iter().map(|m| m.count).sorted().skip(6).product();
-    r.to_string().into()
+    monkeys.iter().map(|m| m.count).sorted().skip(6).product()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = ();
+    type One = usize;
+    type Two = usize;
+
+    const TITLE: Option<&'static str> = Some("Monkey in the Middle");
+
+    fn parse(_: InputIterator) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn part_one(_: &Self::Input) -> Result<Self::One> {
+        Ok(monkey_business(20, 3))
+    }
+
+    fn part_two(_: &Self::Input) -> Result<Self::Two> {
+        Ok(monkey_business(10000, 1))
+    }
 }
 
 #[cfg(test)]
@@ -138,7 +156,7 @@ mod tests {
             ];
         for _ in 0..20 {
             round(&mut monkeys, 3);
-            //println!("0: {}\n1: {}\n2: {}\n3: {}", monkeys[0], monkeys[1], monkeys[2], monkeys[3]);
+            log::debug!("0: {}\n1: {}\n2: {}\n3: {}", monkeys[0], monkeys[1], monkeys[2], monkeys[3]);
         }
         let r: usize = monkeys.iter().map(|m| m.count).sorted().skip(2).product();
         assert_eq!(10605, r);
@@ -155,7 +173,7 @@ mod tests {
         for _ in 0..20 {
             round(&mut monkeys, 1);
         }
-        //println!("0: {}\n1: {}\n2: {}\n3: {}", monkeys[0], monkeys[1], monkeys[2], monkeys[3]);
+        log::debug!("0: {}\n1: {}\n2: {}\n3: {}", monkeys[0], monkeys[1], monkeys[2], monkeys[3]);
         let mut r: usize = monkeys.iter().map(|m| m.count).sorted().skip(2).product();
         assert_eq!(103 * 99, r);
         for _ in 20..2000 {
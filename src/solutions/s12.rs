@@ -1,9 +1,10 @@
 use std::collections::HashSet;
 
-use fast_paths::{FastGraph, InputGraph};
+use anyhow::{Context, ensure, Result};
 use itertools::Itertools;
 
-use crate::{InputIterator, Ztr};
+use crate::{Example, InputError, InputIterator, Solution, Ztr};
+use crate::graph::Graph;
 
 static _TEST_DATA: &str = "Sabqponm
 abcryxxl
@@ -57,13 +58,8 @@ fn adj_map(grid: &[&[i32]]) -> HashSet<(Coord, Coord)> {
         .collect::<HashSet<_>>()
 }
 
-fn one(graph: &FastGraph, w: usize, start: &Coord, finish: &Coord) -> usize {
-    if let Some(shortest_path) = fast_paths::calc_path(graph, start.0 * w + start.1, finish.0 * w + finish.1) {
-        if shortest_path.is_found() {
-            return shortest_path.get_weight()
-        }
-    }
-    0
+fn one(graph: &Graph, w: usize, start: &Coord, finish: &Coord) -> Option<usize> {
+    graph.distance(start.0 * w + start.1, finish.0 * w + finish.1)
 }
 
 fn starting_points(grid: &[&[i32]]) -> Vec<Coord>{
@@ -89,34 +85,69 @@ fn starting_points(grid: &[&[i32]]) -> Vec<Coord>{
     res
 }
 
-pub fn solution(it: InputIterator, part_two: bool) -> Ztr {
-    let mut buf: Vec<i32> = Vec::with_capacity(10000);
-    let (grid_vec, start, finish) = read_to_array(it, &mut buf);
-    let grid = grid_vec.as_slice();
-    let w = grid[0].len();
-    let mut input_graph = InputGraph::new();
-    adj_map(grid).iter()
-        .map(|&(from, to)| (from.0 * w + from.1, to.0 * w + to.1))
-        .for_each(|(from, to)| { input_graph.add_edge(from, to, 1); });
-    input_graph.freeze();
-    let fast_graph = fast_paths::prepare(&input_graph);
-    (if part_two {
-        starting_points(grid).iter()
-            .map(|s| one(&fast_graph, w, s, &finish))
+fn prepare(buf: &[i32], w: usize) -> Graph {
+    let grid_vec = buf.chunks(w).collect::<Vec<_>>();
+    Graph::new(adj_map(grid_vec.as_slice()).iter()
+        .map(|&(from, to)| (from.0 * w + from.1, to.0 * w + to.1, 1)))
+}
+
+/// The height map with the [Graph] of the steps it allows, built once and shared by both parts.
+pub struct HeightMap {
+    graph: Graph,
+    buf: Vec<i32>,
+    w: usize,
+    start: Coord,
+    finish: Coord,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = HeightMap;
+    type One = usize;
+    type Two = usize;
+
+    const TITLE: Option<&'static str> = Some("Hill Climbing Algorithm");
+    const EXAMPLES: &'static [Example] = &[Example { input: _TEST_DATA, one: Some("31"), two: Some("29") }];
+
+    fn parse(it: InputIterator) -> Result<Self::Input> {
+        let mut lines: Vec<Ztr> = Vec::with_capacity(100);
+        for (n, line) in it.enumerate() {
+            if !line.chars().all(|c| c.is_ascii_lowercase() || c == 'S' || c == 'E') {
+                return Err(InputError::new(n, &line, "heights must be a-z, S or E").into())
+            }
+            if lines.first().is_some_and(|first| first.len() != line.len()) {
+                return Err(InputError::new(n, &line, "rows differ in length").into())
+            }
+            lines.push(line);
+        }
+        ensure!(lines.first().is_some_and(|l| !l.is_empty()), "the map is empty");
+        let mut buf: Vec<i32> = Vec::with_capacity(10000);
+        let (grid_vec, start, finish) = read_to_array(&mut lines.into_iter(), &mut buf);
+        let w = grid_vec[0].len();
+        Ok(HeightMap { graph: prepare(&buf, w), buf, w, start, finish })
+    }
+
+    fn part_one(map: &Self::Input) -> Result<Self::One> {
+        one(&map.graph, map.w, &map.start, &map.finish).context("no path from S to E")
+    }
+
+    fn part_two(map: &Self::Input) -> Result<Self::Two> {
+        let grid_vec = map.buf.chunks(map.w).collect::<Vec<_>>();
+        starting_points(grid_vec.as_slice()).iter()
+            .filter_map(|s| one(&map.graph, map.w, s, &map.finish))
             .filter(|weight| *weight != 0)
             .min()
-            .unwrap_or_default()
-    } else {
-        one(&fast_graph, w, &start, &finish)
-    }).to_string().into()
+            .context("no path from any a to E")
+    }
 }
 
 #[test]
 fn test1() {
-    assert_eq!("31", solution(& mut (_TEST_DATA.lines().map(|s| s.into())), false));
+    assert_eq!(31, Day::part_one(&Day::parse(& mut (_TEST_DATA.lines().map(|s| s.into()))).unwrap()).unwrap());
 }
 
 #[test]
 fn test2() {
-    assert_eq!("29", solution(& mut (_TEST_DATA.lines().map(|s| s.into())), true));
+    assert_eq!(29, Day::part_two(&Day::parse(& mut (_TEST_DATA.lines().map(|s| s.into()))).unwrap()).unwrap());
 }
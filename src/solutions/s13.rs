@@ -0,0 +1,143 @@
+use std::cmp::Ordering;
+use std::slice;
+
+use anyhow::{bail, Result};
+use pom::parser::{call, end, list, Parser, sym};
+
+use crate::{Example, InputError, InputIterator, Solution};
+use crate::parsers::integer;
+
+static _TEST_DATA: &str = "[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]
+";
+
+/// A packet of the distress signal, an integer or a list of packets.
+#[derive(Debug, Clone, Eq)]
+pub enum Packet {
+    Int(i32),
+    List(Vec<Packet>),
+}
+
+impl Ord for Packet {
+    /// Integers compare as numbers and lists element by element, the shorter list first when one
+    /// runs out. An integer next to a list compares as the list holding just that integer.
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Packet::Int(l), Packet::Int(r)) => l.cmp(r),
+            (Packet::List(l), Packet::List(r)) => l.cmp(r),
+            (Packet::Int(_), Packet::List(r)) => slice::from_ref(self).cmp(r.as_slice()),
+            (Packet::List(l), Packet::Int(_)) => l.as_slice().cmp(slice::from_ref(other)),
+        }
+    }
+}
+
+/// Equal when [Ord] says so, `[[1]]` equals `1`.
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn packet<'a>() -> Parser<'a, u8, Packet> {
+    let packets = sym(b'[') * list(call(packet), sym(b',')) - sym(b']');
+    integer().map(Packet::Int) | packets.map(Packet::List)
+}
+
+fn parse_line(n: usize, line: &str) -> Result<Packet, InputError> {
+    (packet() - end()).parse(line.as_bytes()).map_err(|e| InputError::new(n, line, e.to_string()))
+}
+
+fn divider(n: i32) -> Packet {
+    Packet::List(vec![Packet::List(vec![Packet::Int(n)])])
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<(Packet, Packet)>;
+    type One = usize;
+    type Two = usize;
+
+    const TITLE: Option<&'static str> = Some("Distress Signal");
+    const EXAMPLES: &'static [Example] = &[Example { input: _TEST_DATA, one: Some("13"), two: Some("140") }];
+
+    fn parse(it: InputIterator) -> Result<Self::Input> {
+        let mut pairs = vec![];
+        let mut left = None;
+        for (n, line) in it.enumerate() {
+            if line.is_empty() {
+                if left.is_some() {
+                    return Err(InputError::new(n, &line, "a packet without a pair").into())
+                }
+                continue
+            }
+            let packet = parse_line(n, &line)?;
+            match left.take() {
+                Some(left) => pairs.push((left, packet)),
+                None => left = Some(packet),
+            }
+        }
+        if left.is_some() {
+            bail!("the last packet has no pair")
+        }
+        Ok(pairs)
+    }
+
+    fn part_one(pairs: &Self::Input) -> Result<Self::One> {
+        Ok(pairs.iter().enumerate()
+            .filter(|(_, (left, right))| left < right)
+            .map(|(n, _)| n + 1)
+            .sum())
+    }
+
+    fn part_two(pairs: &Self::Input) -> Result<Self::Two> {
+        let dividers = [divider(2), divider(6)];
+        let mut packets = pairs.iter().flat_map(|(left, right)| [left, right]).chain(&dividers).collect::<Vec<_>>();
+        packets.sort();
+        Ok(dividers.iter().map(|d| packets.binary_search(&d).map_or(0, |n| n + 1)).product())
+    }
+}
+
+#[test]
+fn mixed() {
+    let packet = |s: &str| parse_line(0, s).unwrap();
+    assert_eq!(Ordering::Equal, packet("[[1],[2]]").cmp(&packet("[1,2]")));
+    assert_eq!(packet("[[1],[2]]"), packet("[1,2]"));
+    assert_ne!(packet("[[1],[2]]"), packet("[1,[2,3]]"));
+    assert!(packet("[[2]]") < packet("[3]"));
+    assert!(packet("[]") < packet("[[]]"));
+    assert!(packet("[[4,4],4,4]") < packet("[[4,4],4,4,4]"));
+}
+
+#[test]
+fn unpaired() {
+    let err = Day::parse(& mut ["[1]", "[2]", "", "[3]", "", "[4]"].into_iter().map(|s| s.into())).unwrap_err();
+    assert_eq!(5, err.downcast_ref::<InputError>().unwrap().line);
+}
@@ -2,9 +2,10 @@ use std::cmp::{max, min};
 use std::collections::HashSet;
 use std::iter::once;
 
-use pom::parser::{list, Parser, seq, sym};
+use anyhow::{Context, Result};
+use pom::parser::{end, list, Parser, seq, sym};
 
-use crate::{InputIterator, Ztr};
+use crate::{Example, InputError, InputIterator, Solution};
 use crate::parsers::integer;
 
 static _TEST_DATA: &str = "498,4 -> 498,6 -> 496,6
@@ -15,10 +16,10 @@ type Coord = (i32, i32);
 
 fn parse_line<'a>() -> Parser<'a, u8, Vec<Coord>> {
     let tuple = integer() - sym(b',').discard() + integer();
-    list(tuple, seq(b" -> "))
+    list(tuple, seq(b" -> ")) - end()
 }
 
-fn create_rocks(points: &[Coord], world: &mut HashSet<Coord>) {
+fn create_rocks(points: &[Coord], world: &mut HashSet<Coord>) -> Result<(), String> {
     let mut prev: Option<&Coord> = None;
     for point in points.iter() {
         if let Some(prev) = prev {
@@ -33,11 +34,12 @@ fn create_rocks(points: &[Coord], world: &mut HashSet<Coord>) {
                             world.insert((*c1, r));
                         })
                 },
-                _ => panic!("but why?")
+                _ => return Err(format!("{:?} -> {:?} is neither horizontal nor vertical", prev, point))
             }
         }
         prev = Some(point);
     }
+    Ok(())
 }
 
 fn advance((c, r): Coord, world: &mut HashSet<Coord>, bottom: i32) -> bool {
@@ -87,29 +89,48 @@ where A: FnMut(Coord) -> bool {
         .unwrap_or_default()
 }
 
-pub fn solution(it: InputIterator, part_two: bool) -> Ztr {
-    let lines = it.collect::<Vec<_>>();
-    let parser = parse_line();
-    let mut world: HashSet<Coord> = HashSet::new();
-    lines.iter().for_each(|line| {
-        if let Ok(coords) = parser.parse(line.as_bytes()) {
-            create_rocks(&coords, &mut world);
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (HashSet<Coord>, i32);
+    type One = usize;
+    type Two = usize;
+
+    const TITLE: Option<&'static str> = Some("Regolith Reservoir");
+    const EXAMPLES: &'static [Example] = &[Example { input: _TEST_DATA, one: Some("24"), two: Some("93") }];
+
+    fn parse(it: InputIterator) -> Result<Self::Input> {
+        let mut world: HashSet<Coord> = HashSet::new();
+        for (n, line) in it.enumerate() {
+            let coords = parse_line().parse(line.as_bytes()).map_err(|e| InputError::new(n, &line, e.to_string()))?;
+            create_rocks(&coords, &mut world).map_err(|reason| InputError::new(n, &line, reason))?;
         }
-    });
-    let bottom = world.iter().map(|&c| c.1).max().unwrap();
-    (if part_two {
-        main_loop(|coord| advance2(coord, &mut world, bottom + 2))
-    } else {
-        main_loop(|coord| advance(coord, &mut world, bottom))
-    }).to_string().into()
+        let bottom = world.iter().map(|&c| c.1).max().context("no rocks in the input")?;
+        Ok((world, bottom))
+    }
+
+    fn part_one((world, bottom): &Self::Input) -> Result<Self::One> {
+        let mut world = world.clone();
+        Ok(main_loop(|coord| advance(coord, &mut world, *bottom)))
+    }
+
+    fn part_two((world, bottom): &Self::Input) -> Result<Self::Two> {
+        let mut world = world.clone();
+        Ok(main_loop(|coord| advance2(coord, &mut world, bottom + 2)))
+    }
 }
 
 #[test]
 fn test1() {
-    assert_eq!("24", solution(& mut (_TEST_DATA.lines().map(|s| s.into())), false));
+    assert_eq!(24, Day::part_one(&Day::parse(& mut (_TEST_DATA.lines().map(|s| s.into()))).unwrap()).unwrap());
 }
 
 #[test]
 fn test2() {
-    assert_eq!("93", solution(& mut (_TEST_DATA.lines().map(|s| s.into())), true));
+    assert_eq!(93, Day::part_two(&Day::parse(& mut (_TEST_DATA.lines().map(|s| s.into()))).unwrap()).unwrap());
+}
+
+#[test]
+fn diagonal() {
+    assert!(Day::parse(& mut ["498,4 -> 500,6"].into_iter().map(|s| s.into())).is_err());
 }
@@ -0,0 +1,165 @@
+use std::collections::HashSet;
+
+use anyhow::{Context, Result};
+use log::debug;
+use pom::parser::{end, Parser, seq, sym};
+
+use crate::{Example, InputError, InputIterator, Solution};
+use crate::intervals::Intervals;
+use crate::parsers::signed;
+
+static _TEST_DATA: &str = "row=10
+bound=20
+Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3
+";
+
+type Coord = (i64, i64);
+
+/// The row part one asks about and the side of the square part two searches.
+const ROW: i64 = 2_000_000;
+const BOUND: i64 = 4_000_000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sensor {
+    at: Coord,
+    beacon: Coord,
+}
+
+impl Sensor {
+    /// How far the sensor sees, there is no beacon closer than this.
+    fn radius(&self) -> i64 {
+        (self.at.0 - self.beacon.0).abs() + (self.at.1 - self.beacon.1).abs()
+    }
+
+    /// The positions of `row` the sensor covers.
+    fn covers(&self, row: i64) -> Option<(i64, i64)> {
+        let reach = self.radius() - (self.at.1 - row).abs();
+        (reach >= 0).then_some((self.at.0 - reach, self.at.0 + reach))
+    }
+}
+
+/// The sensors with the row of part one and the size of the square searched by part two. Those
+/// are [ROW] and [BOUND] unless the input starts with `row=` and `bound=` lines, as the example
+/// does for its smaller area, or the caller sets them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub sensors: Vec<Sensor>,
+    pub row: i64,
+    pub bound: i64,
+}
+
+impl Input {
+    pub fn new(sensors: Vec<Sensor>) -> Self {
+        Input { sensors, row: ROW, bound: BOUND }
+    }
+}
+
+fn position<'a>() -> Parser<'a, u8, Coord> {
+    (seq(b"x=") * signed() - seq(b", y=") + signed()).map(|(x, y)| (x as i64, y as i64))
+}
+
+fn setting<'a>(name: &'static str) -> Parser<'a, u8, i64> {
+    seq(name.as_bytes()) * sym(b'=') * signed().map(|n| n as i64) - end()
+}
+
+fn parse_line<'a>() -> Parser<'a, u8, Sensor> {
+    let sensor = seq(b"Sensor at ") * position() - seq(b": closest beacon is at ") + position() - end();
+    sensor.map(|(at, beacon)| Sensor { at, beacon })
+}
+
+fn coverage(sensors: &[Sensor], row: i64) -> Intervals {
+    sensors.iter().filter_map(|s| s.covers(row)).map(|(start, end)| start..=end).collect()
+}
+
+/// Rows where the edges just outside of two sensors' reach cross, or where one of those edges
+/// meets the left or right side of the square. A single position missed by every sensor inside
+/// the square is hemmed in by their edges and the square's, so it sits on one of these rows or
+/// on the top or bottom one.
+fn crossings(sensors: &[Sensor], bound: i64) -> Vec<i64> {
+    let outside = |s: &Sensor| [-1, 1].map(|side| side * (s.radius() + 1));
+    // edges going down to the right have a constant y - x, the others a constant x + y
+    let falling = sensors.iter().flat_map(|s| outside(s).map(|d| s.at.1 - s.at.0 + d)).collect::<HashSet<_>>();
+    let rising = sensors.iter().flat_map(|s| outside(s).map(|d| s.at.0 + s.at.1 + d)).collect::<HashSet<_>>();
+    let sides = falling.iter().flat_map(|a| [*a, a + bound]).chain(rising.iter().flat_map(|b| [*b, b - bound]));
+    let mut rows = falling.iter()
+        .flat_map(|a| rising.iter().filter(move |b| (a + *b) % 2 == 0).map(move |b| (a + b) / 2))
+        .chain(sides)
+        .collect::<Vec<_>>();
+    rows.sort_unstable();
+    rows.dedup();
+    rows
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Input;
+    type One = u64;
+    type Two = i64;
+
+    const TITLE: Option<&'static str> = Some("Beacon Exclusion Zone");
+    const EXAMPLES: &'static [Example] = &[Example { input: _TEST_DATA, one: Some("26"), two: Some("56000011") }];
+
+    fn parse(it: InputIterator) -> Result<Self::Input> {
+        let mut input = Input::new(vec![]);
+        for (n, line) in it.enumerate() {
+            if let Ok(row) = setting("row").parse(line.as_bytes()) {
+                input.row = row;
+            } else if let Ok(bound) = setting("bound").parse(line.as_bytes()) {
+                input.bound = bound;
+            } else {
+                input.sensors.push(parse_line().parse(line.as_bytes()).map_err(|e| InputError::new(n, &line, e.to_string()))?);
+            }
+        }
+        Ok(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::One> {
+        let covered = coverage(&input.sensors, input.row);
+        let beacons = input.sensors.iter()
+            .map(|s| s.beacon)
+            .filter(|&(x, y)| y == input.row && covered.contains(x))
+            .collect::<HashSet<_>>();
+        Ok(covered.len() - beacons.len() as u64)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Two> {
+        let candidates = crossings(&input.sensors, input.bound);
+        debug!("{} candidate rows", candidates.len());
+        let (x, y) = [0, input.bound].into_iter().chain(candidates)
+            .filter(|row| (0..=input.bound).contains(row))
+            .find_map(|row| coverage(&input.sensors, row).first_gap(0..=input.bound).map(|x| (x, row)))
+            .context("every position is covered")?;
+        Ok(x * 4_000_000 + y)
+    }
+}
+
+#[test]
+fn bad_line() {
+    let err = Day::parse(&mut ["Sensor at x=2, y=18: closest beacon is at x=-2, y=15", "Sensor at x=2, y=-"].into_iter().map(|s| s.into())).unwrap_err();
+    assert_eq!(2, err.downcast_ref::<InputError>().unwrap().line);
+}
+
+#[test]
+fn settings() {
+    let sensor = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15";
+    let input = Day::parse(&mut [sensor].into_iter().map(|s| s.into())).unwrap();
+    assert_eq!((ROW, BOUND), (input.row, input.bound));
+    let input = Day::parse(&mut ["row=10", "bound=20", sensor].into_iter().map(|s| s.into())).unwrap();
+    assert_eq!((10, 20, 1), (input.row, input.bound, input.sensors.len()));
+    let input = Input { row: 18, ..input };
+    assert_eq!(15, Day::part_one(&input).unwrap());
+}
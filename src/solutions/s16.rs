@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use anyhow::{Context, ensure, Result};
+use log::debug;
+use pom::parser::{end, is_a, list, Parser, seq};
+
+use crate::{Example, InputError, InputIterator, Solution};
+use crate::graph::Graph;
+use crate::parsers::integer;
+
+static _TEST_DATA: &str = "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II
+";
+
+const START: &str = "AA";
+
+/// More valves that release pressure than this don't fit the bit masks of [Valves::best].
+const MAX_VALVES: usize = 20;
+
+struct Line {
+    name: String,
+    rate: i32,
+    tunnels: Vec<String>,
+}
+
+fn name<'a>() -> Parser<'a, u8, String> {
+    is_a(|c: u8| c.is_ascii_uppercase()).repeat(2).collect().map(|n| String::from_utf8_lossy(n).to_string())
+}
+
+fn parse_line<'a>() -> Parser<'a, u8, Line> {
+    let valve = seq(b"Valve ") * name() - seq(b" has flow rate=") + integer();
+    let tunnels = (seq(b"; tunnels lead to valves ") | seq(b"; tunnel leads to valve ")) * list(name(), seq(b", "));
+    (valve + tunnels - end()).map(|((name, rate), tunnels)| Line { name, rate, tunnels })
+}
+
+/// The valves worth opening and how many minutes it takes to walk between them. The starting
+/// valve comes last, it's only a place to walk from.
+#[derive(Debug)]
+pub struct Valves {
+    rates: Vec<u32>,
+    /// `None` where there is no way from one valve to the other
+    distances: Vec<Vec<Option<u32>>>,
+}
+
+impl Valves {
+    fn start(&self) -> usize {
+        self.rates.len()
+    }
+
+    /// The most pressure released in `minutes` by a walk opening exactly the valves of each bit
+    /// mask, for every set of valves some walk manages to open.
+    fn best(&self, minutes: u32) -> Vec<u32> {
+        let mut best = vec![0; 1 << self.rates.len()];
+        self.walk(self.start(), minutes, 0, 0, &mut best);
+        best
+    }
+
+    fn walk(&self, at: usize, left: u32, open: usize, released: u32, best: &mut [u32]) {
+        best[open] = best[open].max(released);
+        for (next, &rate) in self.rates.iter().enumerate() {
+            let Some(distance) = self.distances[at][next] else { continue };
+            if open & 1 << next != 0 || distance + 1 >= left {
+                continue
+            }
+            let left = left - distance - 1;
+            self.walk(next, left, open | 1 << next, released + rate * left, best);
+        }
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Valves;
+    type One = u32;
+    type Two = u32;
+
+    const TITLE: Option<&'static str> = Some("Proboscidea Volcanium");
+    const EXAMPLES: &'static [Example] = &[Example { input: _TEST_DATA, one: Some("1651"), two: Some("1707") }];
+
+    fn parse(it: InputIterator) -> Result<Self::Input> {
+        let lines = it.enumerate()
+            .map(|(n, line)| parse_line().parse(line.as_bytes()).map_err(|e| InputError::new(n, &line, e.to_string())))
+            .collect::<Result<Vec<_>, _>>()?;
+        let index = lines.iter().enumerate().map(|(n, l)| (l.name.as_str(), n)).collect::<HashMap<_, _>>();
+        let mut edges = vec![];
+        for (n, line) in lines.iter().enumerate() {
+            for tunnel in &line.tunnels {
+                edges.push((n, *index.get(tunnel.as_str()).with_context(|| format!("valve {} leads to unknown valve {}", line.name, tunnel))?, 1));
+            }
+        }
+        let mut valves = lines.iter().enumerate().filter(|(_, l)| l.rate > 0).map(|(n, _)| n).collect::<Vec<_>>();
+        ensure!(valves.len() <= MAX_VALVES, "{} valves release pressure, at most {} can be handled", valves.len(), MAX_VALVES);
+        valves.push(*index.get(START).with_context(|| format!("there is no valve {}", START))?);
+        debug!("{} valves, {} worth opening", lines.len(), valves.len() - 1);
+        let distances = Graph::new(edges).distances(&valves).into_iter()
+            .map(|row| row.into_iter().map(|d| d.map(|d| d as u32)).collect())
+            .collect();
+        let rates = valves[..valves.len() - 1].iter().map(|&n| lines[n].rate as u32).collect();
+        Ok(Valves { rates, distances })
+    }
+
+    fn part_one(valves: &Self::Input) -> Result<Self::One> {
+        Ok(valves.best(30).into_iter().max().unwrap_or_default())
+    }
+
+    /// The elephant and I open disjoint sets of valves, each on a walk of its own.
+    fn part_two(valves: &Self::Input) -> Result<Self::Two> {
+        let mut best = valves.best(26);
+        // best[mask] becomes the best of any subset of mask
+        for bit in 0..valves.rates.len() {
+            for mask in 0..best.len() {
+                if mask & 1 << bit != 0 {
+                    best[mask] = best[mask].max(best[mask ^ 1 << bit]);
+                }
+            }
+        }
+        let all = best.len() - 1;
+        Ok((0..best.len()).map(|mine| best[mine] + best[all ^ mine]).max().unwrap_or_default())
+    }
+}
+
+#[test]
+fn unknown_tunnel() {
+    let err = Day::parse(&mut ["Valve AA has flow rate=0; tunnel leads to valve BB"].into_iter().map(|s| s.into())).unwrap_err();
+    assert_eq!("valve AA leads to unknown valve BB", err.to_string());
+}
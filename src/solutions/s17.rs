@@ -1,8 +1,13 @@
 use std::cmp;
 use std::cmp::Ordering;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
-use crate::{InputIterator, Ztr};
+use anyhow::{ensure, Result};
+use log::{debug, Level, log_enabled, trace};
+
+use crate::{Example, InputError, InputIterator, Solution};
+
+static _TEST_DATA: &str = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
 
 trait Shape {
     // type World;
@@ -74,7 +79,7 @@ impl VecShape {
         if self.collides(world) {
             *self = old;
         }
-        //println!("after pushing {}: {:?}", dir, self);
+        trace!("after pushing {}: {:?}", dir, self);
     }
 
     pub fn collides(&self, world: &HashSet<Coord>) -> bool {
@@ -92,7 +97,7 @@ impl VecShape {
             *self = new;
             None
         };
-        //println!("after down: {:?}", self);
+        trace!("after down: {:?}", self);
         r
     }
 
@@ -103,14 +108,13 @@ impl VecShape {
 
 }
 
-#[allow(unused)]
 fn print_world(world: &HashSet<Coord>) {
     let top = world.iter().map(|&(r, _)| r).max().unwrap_or_default();
     for r in (1..=top).rev() {
         let line: String = (0..7).map(|c| if world.contains(&(r, c)) {'#'} else { '.' }).collect();
-        println!("{:04} |{}|", r, line);
+        trace!("{:04} |{}|", r, line);
     }
-    println!("0000 +-------+");
+    trace!("0000 +-------+");
 }
 
 fn find_period<T: Ord>(inp: &[T]) -> Option<usize> {
@@ -130,42 +134,91 @@ fn any_period() {
     assert_eq!(Some(6), find_period(&vec![1, 1, 1, 0, 0, 0, 1, 1, 1, 0, 0, 0, 1, 1, 1, ]));
 }
 
-pub fn solution(it: InputIterator, part_two: bool) -> Ztr {
+/// Height of the tower after `rocks` rocks. With `skip_repeats`, once a rock falls in the same
+/// place of the rock and jet cycles onto the same surface as an earlier one, the tower is taken
+/// to repeat from there, and the whole repeats left are added up without dropping their rocks.
+///
+/// That is a heuristic: the surface is only the depth of each column below the top, a rock could
+/// still slip down a hole that differs further below. It holds for the puzzle's inputs.
+fn tower_height(dir_vec: &[char], rocks: usize, skip_repeats: bool) -> i64 {
     let mut world: HashSet<Coord> = HashSet::new();
-    let dir_vec = it.next().iter().flat_map(|l| l.chars()).collect::<Vec<_>>();
-    let mut directions = dir_vec.iter().cycle();
-    let rocks = if part_two { 1000000000000 } else { 2022 };
-    let r = (0..5).cycle()
-        .take(rocks)
-        .enumerate()
-        .fold(0_i64, |last_top, (_, n)| {
-            //println!("last top {}", last_top);
-            let mut shape = VecShape::at_height(n, last_top + 4);
-            let top = directions.by_ref()
-                .map(|&dir| shape.step(dir, &mut world))
-                .skip_while(|r| r.is_none())
-                .next()
-                .flatten()
-                .unwrap_or_default();
-            cmp::max(top, last_top)
-        });
-    //print_world(&world);
-    r.to_string().into()
+    // highest rock in each column
+    let mut columns = [0_i64; 7];
+    let mut seen = HashMap::new();
+    let mut jet = 0;
+    let mut top = 0;
+    let mut repeated = 0;
+    let mut n = 0;
+    while n < rocks {
+        trace!("last top {}", top);
+        let mut shape = VecShape::at_height(n % 5, top + 4);
+        let landed = loop {
+            let dir = dir_vec[jet];
+            jet = (jet + 1) % dir_vec.len();
+            if let Some(landed) = shape.step(dir, &mut world) {
+                break landed
+            }
+        };
+        top = cmp::max(top, landed);
+        shape.0.iter().for_each(|&(r, c)| columns[c as usize] = cmp::max(columns[c as usize], r));
+        n += 1;
+        if skip_repeats && repeated == 0 {
+            if let Some((before, height)) = seen.insert((n % 5, jet, columns.map(|c| top - c)), (n, top)) {
+                let repeats = (rocks - n) / (n - before);
+                debug!("rocks {} to {} repeat, {} more times", before, n, repeats);
+                repeated = repeats as i64 * (top - height);
+                n += repeats * (n - before);
+            }
+        }
+    }
+    if log_enabled!(Level::Trace) {
+        print_world(&world);
+    }
+    top + repeated
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<char>;
+    type One = i64;
+    type Two = i64;
+
+    const TITLE: Option<&'static str> = Some("Pyroclastic Flow");
+    const EXAMPLES: &'static [Example] = &[Example { input: _TEST_DATA, one: Some("3068"), two: Some("1514285714288") }];
+
+    fn parse(it: InputIterator) -> Result<Self::Input> {
+        let line = it.next().unwrap_or_default();
+        if let Some(bad) = line.chars().find(|&c| c != '<' && c != '>') {
+            return Err(InputError::new(0, &line, format!("unexpected jet {:?}", bad)).into())
+        }
+        ensure!(!line.is_empty(), "no jets in the input");
+        Ok(line.chars().collect())
+    }
+
+    fn part_one(dir_vec: &Self::Input) -> Result<Self::One> {
+        Ok(tower_height(dir_vec, 2022, true))
+    }
+
+    fn part_two(dir_vec: &Self::Input) -> Result<Self::Two> {
+        Ok(tower_height(dir_vec, 1000000000000, true))
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
-    static _TEST_DATA: &str = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
-
     #[test]
     fn test1() {
-        assert_eq!("3068", solution(&mut (_TEST_DATA.lines().map(|s| s.into())), false));
+        assert_eq!(3068, Day::part_one(&Day::parse(&mut (_TEST_DATA.lines().map(|s| s.into()))).unwrap()).unwrap());
     }
 
-// #[test]
-// fn test2() {
-//     assert_eq!("1514285714288", solution(& mut (_TEST_DATA.lines().map(|s| s.into())), true))
-// }
+    #[test]
+    fn repeats() {
+        let jets = _TEST_DATA.chars().collect::<Vec<_>>();
+        for rocks in [1, 15, 100, 2022, 3001, 4567] {
+            assert_eq!(tower_height(&jets, rocks, false), tower_height(&jets, rocks, true), "{} rocks", rocks);
+        }
+    }
 }
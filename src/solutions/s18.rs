@@ -1,7 +1,34 @@
 use std::collections::HashSet;
 use std::str::FromStr;
 
-use crate::{InputIterator, Ztr};
+use anyhow::Result;
+
+use crate::{Example, InputError, InputIterator, Solution};
+
+static _TEST_DATA: &str = "2,2,2
+1,2,2
+3,2,2
+2,1,2
+2,3,2
+2,2,1
+2,2,3
+2,2,4
+2,2,6
+1,2,5
+3,2,5
+2,1,5
+2,3,5
+";
+
+fn parse_cube(n: usize, line: &str) -> Result<(i32, i32, i32), InputError> {
+    let coords = line.split(',')
+                     .map(|coord| i32::from_str(coord).map_err(|e| InputError::new(n, line, e.to_string())))
+                     .collect::<Result<Vec<_>, _>>()?;
+    match coords[..] {
+        [x, y, z] => Ok((x, y, z)),
+        _ => Err(InputError::new(n, line, "expected three coordinates"))
+    }
+}
 
 fn num_neighbours(&(x, y, z): &(i32, i32, i32), world: &HashSet<(i32, i32, i32)>) -> i32 {
     let mut n = 0;
@@ -26,18 +53,33 @@ fn num_neighbours(&(x, y, z): &(i32, i32, i32), world: &HashSet<(i32, i32, i32)>
     n
 }
 
-pub fn solution(it: InputIterator, part_two: bool) -> Ztr {
-    let mut sides: i32 = 0;
-    let mut cubes: HashSet<(i32, i32, i32)> = HashSet::new();
-    it.map(|line| {
-            let mut coords = line.split(",").map(|coord| i32::from_str(coord).unwrap_or_default());
-            (coords.next().unwrap_or_default(), coords.next().unwrap_or_default(), coords.next().unwrap_or_default())
-        })
-        .for_each(|cube| {
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (HashSet<(i32, i32, i32)>, i32);
+    type One = i32;
+    type Two = i32;
+
+    const TITLE: Option<&'static str> = Some("Boiling Boulders");
+    const EXAMPLES: &'static [Example] = &[Example { input: _TEST_DATA, one: Some("64"), two: Some("58") }];
+
+    fn parse(it: InputIterator) -> Result<Self::Input> {
+        let mut sides: i32 = 0;
+        let mut cubes: HashSet<(i32, i32, i32)> = HashSet::new();
+        for (n, line) in it.enumerate() {
+            let cube = parse_cube(n, &line)?;
             sides += 6 - num_neighbours(&cube, &cubes) * 2;
             cubes.insert(cube);
-        });
-    (if part_two {
+        }
+        Ok((cubes, sides))
+    }
+
+    fn part_one((_, sides): &Self::Input) -> Result<Self::One> {
+        Ok(*sides)
+    }
+
+    fn part_two((cubes, sides): &Self::Input) -> Result<Self::Two> {
+        let mut sides = *sides;
         let xmax = cubes.iter().map(|&(x, _, _)| x).max().unwrap_or_default();
         let ymax = cubes.iter().map(|&(_, y, _)| y).max().unwrap_or_default();
         let zmax = cubes.iter().map(|&(_, _, z)| z).max().unwrap_or_default();
@@ -46,7 +88,7 @@ pub fn solution(it: InputIterator, part_two: bool) -> Ztr {
                 for x in 1..=xmax {
                     let cube = (x, y, z);
                     if !cubes.contains(&cube) {
-                        sides -= match num_neighbours(&cube, &cubes) {
+                        sides -= match num_neighbours(&cube, cubes) {
                             n @ 3..=6 => n,
                             _ => 0
                         }
@@ -54,38 +96,27 @@ pub fn solution(it: InputIterator, part_two: bool) -> Ztr {
                 }
             }
         }
-        sides
-    } else {
-        sides
-    }).to_string().into()
+        Ok(sides)
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
 
-    static _TEST_DATA: &str = "2,2,2
-1,2,2
-3,2,2
-2,1,2
-2,3,2
-2,2,1
-2,2,3
-2,2,4
-2,2,6
-1,2,5
-3,2,5
-2,1,5
-2,3,5
-";
-
     #[test]
     fn test1() {
-        assert_eq!("64", solution(&mut (_TEST_DATA.lines().map(|s| s.into())), false));
+        assert_eq!(64, Day::part_one(&Day::parse(&mut (_TEST_DATA.lines().map(|s| s.into()))).unwrap()).unwrap());
     }
 
     #[test]
     fn test2() {
-        assert_eq!("58", solution(&mut (_TEST_DATA.lines().map(|s| s.into())), true));
+        assert_eq!(58, Day::part_two(&Day::parse(&mut (_TEST_DATA.lines().map(|s| s.into()))).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn truncated() {
+        assert_eq!(2, parse_cube(1, "2,2").unwrap_err().line);
+        assert!(parse_cube(1, "2,x,2").is_err());
     }
 }
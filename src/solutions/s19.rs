@@ -0,0 +1,186 @@
+use std::panic;
+use std::thread;
+
+use anyhow::Result;
+use log::debug;
+use pom::parser::{end, Parser, seq};
+
+use crate::{Example, InputError, InputIterator, Solution};
+use crate::parsers::integer;
+use crate::search::{maximize, Problem};
+
+static _TEST_DATA: &str = "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
+Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.
+";
+
+const ORE: usize = 0;
+const CLAY: usize = 1;
+const OBSIDIAN: usize = 2;
+const GEODE: usize = 3;
+
+/// What each robot costs in ore, clay and obsidian, by the kind of robot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Blueprint {
+    id: u32,
+    costs: [[u32; 3]; 4],
+}
+
+fn parse_line<'a>() -> Parser<'a, u8, Blueprint> {
+    let number = || integer().map(|n| n as u32);
+    let blueprint = seq(b"Blueprint ") * number()
+        - seq(b": Each ore robot costs ") + number()
+        - seq(b" ore. Each clay robot costs ") + number()
+        - seq(b" ore. Each obsidian robot costs ") + number() - seq(b" ore and ") + number()
+        - seq(b" clay. Each geode robot costs ") + number() - seq(b" ore and ") + number()
+        - seq(b" obsidian.") - end();
+    blueprint.map(|((((((id, ore), clay), obsidian_ore), obsidian_clay), geode_ore), geode_obsidian)| Blueprint {
+        id,
+        costs: [[ore, 0, 0], [clay, 0, 0], [obsidian_ore, obsidian_clay, 0], [geode_ore, 0, geode_obsidian]],
+    })
+}
+
+#[derive(Debug, Clone)]
+struct State {
+    left: u32,
+    robots: [u32; 4],
+    stock: [u32; 4],
+}
+
+/// Opening geodes with the robots of one blueprint.
+struct Factory<'a> {
+    blueprint: &'a Blueprint,
+    /// no more robots of a kind than the most any robot costs of what they collect, a robot can
+    /// only be built once a minute
+    caps: [u32; 3],
+}
+
+impl<'a> Factory<'a> {
+    fn new(blueprint: &'a Blueprint) -> Self {
+        let caps = [ORE, CLAY, OBSIDIAN].map(|r| blueprint.costs.iter().map(|c| c[r]).max().unwrap_or_default());
+        Factory { blueprint, caps }
+    }
+
+    fn geodes(&self, minutes: u32) -> u32 {
+        maximize(self, State { left: minutes, robots: [1, 0, 0, 0], stock: [0; 4] })
+    }
+
+    /// Minutes until a robot of kind `robot` is ready when the factory waits for it and builds
+    /// nothing else, `None` while nothing collects a resource it needs.
+    fn wait(&self, state: &State, robot: usize) -> Option<u32> {
+        let mut wait = 0;
+        for (r, &cost) in self.blueprint.costs[robot].iter().enumerate() {
+            if cost > state.stock[r] {
+                if state.robots[r] == 0 {
+                    return None
+                }
+                wait = wait.max((cost - state.stock[r]).div_ceil(state.robots[r]));
+            }
+        }
+        Some(wait + 1)
+    }
+}
+
+impl Problem for Factory<'_> {
+    type State = State;
+    type Value = u32;
+
+    /// The geodes at the end if no more robots get built.
+    fn value(&self, state: &State) -> u32 {
+        state.stock[GEODE] + state.robots[GEODE] * state.left
+    }
+
+    /// As if a geode robot got built every minute from now on.
+    fn bound(&self, state: &State) -> u32 {
+        self.value(state) + state.left * state.left.saturating_sub(1) / 2
+    }
+
+    /// Skips ahead to the building of each kind of robot that is still worth building, the
+    /// geode robot last so that it is explored first.
+    fn branch(&self, state: &State, next: &mut Vec<State>) {
+        for robot in [ORE, CLAY, OBSIDIAN, GEODE] {
+            if robot != GEODE && state.robots[robot] >= self.caps[robot] {
+                continue
+            }
+            let Some(wait) = self.wait(state, robot).filter(|&wait| wait < state.left) else { continue };
+            let mut built = State { left: state.left - wait, ..state.clone() };
+            for r in 0..4 {
+                built.stock[r] += state.robots[r] * wait;
+            }
+            for (r, &cost) in self.blueprint.costs[robot].iter().enumerate() {
+                built.stock[r] -= cost;
+            }
+            built.robots[robot] += 1;
+            next.push(built);
+        }
+    }
+}
+
+/// The geodes each blueprint opens in `minutes`, searched on a thread per blueprint.
+fn geodes(blueprints: &[Blueprint], minutes: u32) -> Vec<u32> {
+    thread::scope(|scope| {
+        let searches = blueprints.iter()
+            .map(|blueprint| scope.spawn(move || Factory::new(blueprint).geodes(minutes)))
+            .collect::<Vec<_>>();
+        searches.into_iter().zip(blueprints).map(|(search, blueprint)| {
+            let geodes = search.join().unwrap_or_else(|e| panic::resume_unwind(e));
+            debug!("blueprint {}: {} geodes in {} minutes", blueprint.id, geodes, minutes);
+            geodes
+        }).collect()
+    })
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Blueprint>;
+    type One = u32;
+    type Two = u32;
+
+    const TITLE: Option<&'static str> = Some("Not Enough Minerals");
+    const EXAMPLES: &'static [Example] = &[Example { input: _TEST_DATA, one: Some("33"), two: Some("3472") }];
+
+    fn parse(it: InputIterator) -> Result<Self::Input> {
+        Ok(it.enumerate()
+            .map(|(n, line)| parse_line().parse(line.as_bytes()).map_err(|e| InputError::new(n, &line, e.to_string())))
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part_one(blueprints: &Self::Input) -> Result<Self::One> {
+        Ok(geodes(blueprints, 24).iter().zip(blueprints).map(|(geodes, b)| geodes * b.id).sum())
+    }
+
+    fn part_two(blueprints: &Self::Input) -> Result<Self::Two> {
+        Ok(geodes(&blueprints[..blueprints.len().min(3)], 32).iter().product())
+    }
+}
+
+#[cfg(test)]
+fn blueprints() -> Vec<Blueprint> {
+    Day::parse(&mut (_TEST_DATA.lines().map(|s| s.into()))).unwrap()
+}
+
+#[test]
+fn first_blueprint() {
+    let blueprints = blueprints();
+    assert_eq!(9, Factory::new(&blueprints[0]).geodes(24));
+}
+
+/// The best a full search without cutting anything off finds from `state`, checking on the way
+/// that no bound is below it.
+#[cfg(test)]
+fn exhaustive(factory: &Factory, state: &State) -> u32 {
+    let mut next = vec![];
+    factory.branch(state, &mut next);
+    let best = next.iter().map(|s| exhaustive(factory, s)).fold(factory.value(state), u32::max);
+    assert!(factory.bound(state) >= best, "bound {} below {} at {:?}", factory.bound(state), best, state);
+    best
+}
+
+#[test]
+fn bound_holds() {
+    for blueprint in &blueprints() {
+        let factory = Factory::new(blueprint);
+        let start = State { left: 24, robots: [1, 0, 0, 0], stock: [0; 4] };
+        assert_eq!(exhaustive(&factory, &start), factory.geodes(24));
+    }
+}
@@ -1,8 +1,19 @@
 use std::str::FromStr;
 
+use anyhow::{ensure, Result};
+use log::{debug, trace};
 use skiplist::SkipList;
 
-use crate::{InputIterator, Ztr};
+use crate::{Example, InputError, InputIterator, Solution};
+
+static _TEST_DATA: &str = "1
+2
+-3
+3
+-2
+0
+4
+";
 
 #[derive(Debug, Default, Copy, Clone)]
 struct Item {
@@ -23,20 +34,17 @@ fn next(cur: i64, shift: i64, size: i64) -> (usize, usize) {
     (n as usize, if n > cur { 0 } else { 1 } as usize)
 }
 
-pub fn solution(it: InputIterator, part_two: bool) -> Ztr {
-    let key = if part_two { 811589153 } else { 1 };
-    let rounds = if part_two { 10 } else { 1 };
+fn mix(input: &[i64], key: i64, rounds: i32) -> i64 {
     let mut size = 0;
-    let mut numbers: SkipList<Item> = it
-        .filter_map(|line| i64::from_str(&line).ok())
+    let mut numbers: SkipList<Item> = input.iter()
         .inspect(|_| { size += 1; })
         .enumerate()
         .map(|(num, val)| Item { val: val * key, round: 0, num })
         .collect();
-    println!("size {}", size);
+    debug!("size {}", size);
     let mut current = 0;
     for current_round in 1..=rounds {
-        println!("round {}", current_round);
+        debug!("round {}", current_round);
         let mut to_handle = size;
         let mut item_to_handle = 0_usize;
         while to_handle > 0 {
@@ -48,7 +56,7 @@ pub fn solution(it: InputIterator, part_two: bool) -> Ztr {
                     numbers.remove(current);
                     let nxt = next(current as i64, val, size as i64);
                     current += nxt.1;
-                    //println!("{} got {}", val, index);
+                    trace!("{} got {}", val, nxt.0);
                     numbers.insert(Item { round: current_round, ..item }, nxt.0);
                 } else {
                     numbers[current].round = current_round;
@@ -60,24 +68,43 @@ pub fn solution(it: InputIterator, part_two: bool) -> Ztr {
             current = current % size;
         }
     }
-    //println!("{:?}", numbers.iter().map(|i| i.val).collect::<Vec<_>>());
+    trace!("{:?}", numbers.iter().map(|i| i.val).collect::<Vec<_>>());
     let zpos = numbers.iter().position(|i| i.val == 0).unwrap_or_default();
-    let r: i64 = vec![1000, 2000, 3000].into_iter().map(|n| numbers[(zpos + n) % size].val).sum();
-    r.to_string().into()
+    vec![1000, 2000, 3000].into_iter().map(|n| numbers[(zpos + n) % size].val).sum()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<i64>;
+    type One = i64;
+    type Two = i64;
+
+    const TITLE: Option<&'static str> = Some("Grove Positioning System");
+    const EXAMPLES: &'static [Example] = &[Example { input: _TEST_DATA, one: Some("3"), two: Some("1623178306") }];
+
+    fn parse(it: InputIterator) -> Result<Self::Input> {
+        let numbers = it.enumerate()
+                        .filter(|(_, line)| !line.is_empty())
+                        .map(|(n, line)| i64::from_str(&line).map_err(|e| InputError::new(n, &line, e.to_string())))
+                        .collect::<Result<Vec<_>, _>>()?;
+        ensure!(numbers.contains(&0), "the file has no 0 to count the coordinates from");
+        Ok(numbers)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::One> {
+        Ok(mix(input, 1, 1))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Two> {
+        Ok(mix(input, 811589153, 10))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    static _TEST_DATA: &str = "1
-2
--3
-3
--2
-0
-4
-";
     #[test]
     fn test0() {
         assert_eq!((3, 0), next(2, 1, 5));
@@ -90,11 +117,16 @@ mod tests {
 
     #[test]
     fn test1() {
-        assert_eq!("3", solution(& mut (_TEST_DATA.lines().map(|s| s.into())), false));
+        assert_eq!(3, Day::part_one(&Day::parse(& mut (_TEST_DATA.lines().map(|s| s.into()))).unwrap()).unwrap());
     }
 
     #[test]
     fn test2() {
-        assert_eq!("1623178306", solution(& mut (_TEST_DATA.lines().map(|s| s.into())), true));
+        assert_eq!(1623178306, Day::part_two(&Day::parse(& mut (_TEST_DATA.lines().map(|s| s.into()))).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn no_zero() {
+        assert!(Day::parse(& mut ["1", "2"].into_iter().map(|s| s.into())).is_err());
     }
 }
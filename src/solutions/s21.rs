@@ -1,57 +1,82 @@
 use std::collections::HashMap;
 use std::str::{from_utf8, FromStr};
 
+use anyhow::{bail, Context, Result};
 use regex::bytes::Regex::{self};
 
 use Monkey::*;
 
-use crate::{InputIterator, Ztr};
+use crate::{Example, InputError, InputIterator, Solution};
+
+static _TEST_DATA: &str = "root: pppw + sjmn
+dbpl: 5
+cczh: sllz + lgvd
+zczc: 2
+ptdq: humn - dvpt
+dvpt: 3
+lfqf: 4
+humn: 5
+ljgn: 2
+sjmn: drzm * dbpl
+sllz: 4
+pppw: cczh / lfqf
+lgvd: ljgn * ptdq
+drzm: hmdt - zczc
+hmdt: 32
+";
 
 type Name = u32;
 
 type Mm = HashMap<Name, Monkey>;
 
 #[derive(Debug, Copy, Clone)]
-enum Monkey {
+pub enum Monkey {
     YELL(Name, Option<i64>),
     WAIT(Name, Name, Name, char),
     ROOT(Name, Name, Name),
 }
 
-fn eval(mm: &Mm, name: &Name) -> Option<i64> {
-    match mm.get(name) {
+fn show(name: &Name) -> String {
+    String::from_utf8_lossy(&name.to_be_bytes()).into_owned()
+}
+
+fn apply(op: char, op1: i64, op2: i64) -> Result<i64> {
+    Ok(match op {
+        '+' => op1 + op2,
+        '-' => op1 - op2,
+        '*' => op1 * op2,
+        '/' if op2 == 0 => bail!("division by zero"),
+        '/' => op1 / op2,
+        _ => bail!("unsupported op: {}", op)
+    })
+}
+
+fn eval(mm: &Mm, name: &Name) -> Result<Option<i64>> {
+    Ok(match mm.get(name) {
         Some(&YELL(_, n)) => n,
-        Some(WAIT(_, l, r, op)) => {
-            if let Some(op1) = eval(mm, l) {
-                if let Some(op2) = eval(mm, r) {
-                    return Some(match *op {
-                        '+' => op1 + op2,
-                        '-' => op1 - op2,
-                        '*' => op1 * op2,
-                        '/' => op1 / op2,
-                        _ => panic!("unsupported op: {}", *op)
-                    })
-                }
+        Some(&WAIT(_, l, r, op)) => {
+            match (eval(mm, &l)?, eval(mm, &r)?) {
+                (Some(op1), Some(op2)) => Some(apply(op, op1, op2)?),
+                _ => None
             }
-            None
         }
-        Some(ROOT(_, l, r)) => {
-            match (eval(mm, l), eval(mm, r)) {
-                (None, Some(n)) => Some(solve(mm, l, n)),
-                (Some(n), None) => Some(solve(mm, r, n)),
-                _ => panic!("shouldn't happen")
+        Some(&ROOT(_, l, r)) => {
+            match (eval(mm, &l)?, eval(mm, &r)?) {
+                (None, Some(n)) => Some(solve(mm, &l, n)?),
+                (Some(n), None) => Some(solve(mm, &r, n)?),
+                _ => bail!("exactly one side of root has to depend on humn")
             }
         }
-        _ => panic!("No def for monkey named {:?}", name)
-    }
+        None => bail!("No def for monkey named {}", show(name))
+    })
 }
 
-fn solve(mm: &Mm, name: &Name, i: i64) -> i64 {
+fn solve(mm: &Mm, name: &Name, i: i64) -> Result<i64> {
     match mm.get(name) {
-        Some(&YELL(_, None)) => i, // THE answer
-        Some(&YELL(_, Some(n))) => n,
+        Some(&YELL(_, None)) => Ok(i), // THE answer
+        Some(&YELL(_, Some(n))) => Ok(n),
         Some(&WAIT(_, l, r, op)) => {
-            match (op, eval(mm, &l), eval(mm, &r)) {
+            match (op, eval(mm, &l)?, eval(mm, &r)?) {
                 ('+', None, Some(n)) => solve(mm, &l, i - n),
                 ('+', Some(n), None) => solve(mm, &r, i - n),
                 ('-', None, Some(n)) => solve(mm, &l, i + n),
@@ -60,19 +85,11 @@ fn solve(mm: &Mm, name: &Name, i: i64) -> i64 {
                 ('*', Some(n), None) => solve(mm, &r, i / n),
                 ('/', None, Some(n)) => solve(mm, &l, i * n),
                 ('/', Some(n), None) => solve(mm, &r, n / i),
-                (op, Some(op1), Some(op2)) => {
-                    match op {
-                        '+' => op1 + op2,
-                        '-' => op1 - op2,
-                        '*' => op1 * op2,
-                        '/' => op1 / op2,
-                        _ => panic!("unsupported op: {}", op)
-                    }
-                }
-                _ => panic!("nooo")
+                (op, Some(op1), Some(op2)) => apply(op, op1, op2),
+                _ => bail!("humn is on both sides of {}", show(name))
             }
         },
-        _ => panic!("No def for monkey named {:?}", name)
+        _ => bail!("No def for monkey named {}", show(name))
     }
 }
 
@@ -80,64 +97,74 @@ fn to_name(n: &[u8]) -> u32 {
     (n[0] as u32) << 24 | (n[1] as u32) << 16 | (n[2] as u32) << 8 | n[3] as u32
 }
 
-pub fn solution(it: InputIterator, part_two: bool) -> Ztr {
-    let mut mm: Mm = HashMap::with_capacity(1000);
-    let wait = Regex::new(r"(\w{4}): (\w{4}) ([+-/*]) (\w{4})").unwrap();
-    let yell = Regex::new(r"(\w{4}): (\d+)").unwrap();
-    let root = to_name(b"root");
-    let humn = to_name(b"humn");
-    for line in it {
-        let l = line.as_bytes();
-        wait.captures(l).into_iter().for_each(|c| {
-            let n = to_name(&c[1]);
-            if part_two && n == root {
-                mm.insert(n, ROOT(n, to_name(&c[2]), to_name(&c[4])));
-            } else {
-                mm.insert(n, WAIT(n, to_name(&c[2]), to_name(&c[4]), c[3][0] as char));
-            }
-        });
-        yell.captures(l).into_iter().for_each(|c| {
-            let n = to_name(&c[1]);
-            if part_two && n == humn {
-                mm.insert(n, YELL(n, None));
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Mm;
+    type One = i64;
+    type Two = i64;
+
+    const TITLE: Option<&'static str> = Some("Monkey Math");
+    const EXAMPLES: &'static [Example] = &[Example { input: _TEST_DATA, one: Some("152"), two: Some("301") }];
+
+    fn parse(it: InputIterator) -> Result<Self::Input> {
+        let mut mm: Mm = HashMap::with_capacity(1000);
+        let wait = Regex::new(r"^(\w{4}): (\w{4}) ([-+*/]) (\w{4})$")?;
+        let yell = Regex::new(r"^(\w{4}): (\d+)$")?;
+        for (n, line) in it.enumerate() {
+            let l = line.as_bytes();
+            if let Some(c) = wait.captures(l) {
+                let name = to_name(&c[1]);
+                mm.insert(name, WAIT(name, to_name(&c[2]), to_name(&c[4]), c[3][0] as char));
+            } else if let Some(c) = yell.captures(l) {
+                let name = to_name(&c[1]);
+                let val = from_utf8(&c[2]).ok()
+                    .and_then(|v| i64::from_str(v).ok())
+                    .ok_or_else(|| InputError::new(n, &line, "number out of range"))?;
+                mm.insert(name, YELL(name, Some(val)));
             } else {
-                let val = i64::from_str(from_utf8(&c[2]).unwrap()).unwrap_or_default();
-                mm.insert(n, YELL(n, Some(val)));
+                return Err(InputError::new(n, &line, "expected \"name: number\" or \"name: name op name\"").into())
             }
-        });
+        }
+        Ok(mm)
+    }
+
+    fn part_one(mm: &Self::Input) -> Result<Self::One> {
+        eval(mm, &to_name(b"root"))?.context("root has no number to yell")
+    }
+
+    fn part_two(mm: &Self::Input) -> Result<Self::Two> {
+        let root = to_name(b"root");
+        let humn = to_name(b"humn");
+        let mut mm = mm.clone();
+        if let Some(&WAIT(n, l, r, _)) = mm.get(&root) {
+            mm.insert(n, ROOT(n, l, r));
+        }
+        mm.insert(humn, YELL(humn, None));
+        eval(&mm, &root)?.context("root has no number to yell")
     }
-    eval(&mm, &root).unwrap_or_default().to_string().into()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    static _TEST_DATA: &str = "root: pppw + sjmn
-dbpl: 5
-cczh: sllz + lgvd
-zczc: 2
-ptdq: humn - dvpt
-dvpt: 3
-lfqf: 4
-humn: 5
-ljgn: 2
-sjmn: drzm * dbpl
-sllz: 4
-pppw: cczh / lfqf
-lgvd: ljgn * ptdq
-drzm: hmdt - zczc
-hmdt: 32
-";
-
     #[test]
     fn test1() {
-        assert_eq!("152", solution(& mut (_TEST_DATA.lines().map(|s| s.into())), false));
+        assert_eq!(152, Day::part_one(&Day::parse(& mut (_TEST_DATA.lines().map(|s| s.into()))).unwrap()).unwrap());
     }
 
     #[test]
     fn test2() {
-        assert_eq!("301", solution(& mut (_TEST_DATA.lines().map(|s| s.into())), true));
+        assert_eq!(301, Day::part_two(&Day::parse(& mut (_TEST_DATA.lines().map(|s| s.into()))).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn missing_monkey() {
+        let mm = Day::parse(& mut (_TEST_DATA.lines().filter(|l| !l.starts_with("hmdt")).map(|s| s.into()))).unwrap();
+        assert!(Day::part_one(&mm).is_err());
+        assert_eq!(3, Day::parse(& mut ["root: pppw + sjmn", "dbpl: 5", "cczh: sllz % lgvd"].into_iter().map(|s| s.into())).unwrap_err()
+            .downcast_ref::<InputError>().unwrap().line);
     }
 
 }
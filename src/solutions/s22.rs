@@ -2,14 +2,32 @@ use std::collections::HashSet;
 use std::iter::Peekable;
 use std::vec::IntoIter;
 
-use pom::parser::{empty, list, one_of, Parser};
+use anyhow::{bail, Result};
+use log::{debug, trace};
+use pom::parser::{empty, end, list, Parser, sym};
 
 use Command::*;
 use Facing::*;
 
-use crate::{Coord, InputIterator, Ztr};
+use crate::{Coord, Example, InputError, InputIterator, Solution};
 use crate::parsers::integer;
 
+static _TEST_DATA: &str = "        ...#
+        .#..
+        #...
+        ....
+...#.......#
+........#...
+..#....#....
+..........#.
+        ...#....
+        .....#..
+        .#......
+        ......#.
+
+10R5L5R10L4R5L5
+";
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 enum Facing {
     R = 0,
@@ -104,22 +122,16 @@ fn steps<'a>() -> Parser<'a, u8, Vec<Command>> {
 }
 
 fn dir<'a>() -> Parser<'a, u8, Vec<Command>> {
-    one_of(b"RL").map(|t| {
-        vec![match t {
-                b'R' => Command::RIGHT,
-                b'L' => Command::LEFT,
-                _ => panic!("bad bad bad")
-            }
-        ]
-    })
+    sym(b'R').map(|_| vec![Command::RIGHT]) | sym(b'L').map(|_| vec![Command::LEFT])
 }
 
 fn path<'a>() -> Parser<'a, u8, Vec<Command>> {
     list(steps() | dir(), empty())
-        .map(|vv| vv.into_iter().flatten().collect())
+        .map(|vv| vv.into_iter().flatten().collect::<Vec<_>>()) - end()
 }
 
-struct World {
+#[derive(Clone)]
+pub struct World {
     walls: HashSet<Coord>,
     regions: Vec<Region>,
     fac: Facing,
@@ -147,13 +159,15 @@ fn fast_forward(iter: &mut Peekable<IntoIter<Command>>) {
 }
 
 impl World {
-    fn new(it: InputIterator) -> Self {
+    fn new(it: InputIterator) -> Result<Self> {
+        let mut path_line = 0;
         let mut start_col = i32::MAX;
         let mut lbound = i32::MAX;
         let mut rbound = i32::MAX;
         let mut regions = vec![];
         let mut walls: HashSet<Coord> = HashSet::with_capacity(1000);
         for (row, line) in it.enumerate() {
+            path_line = row + 1;
             let (lb, rb) = boundaries(&line);
             if lbound == i32::MAX {
                 start_col = lb;
@@ -177,18 +191,17 @@ impl World {
                 }
             }
         }
-        let commands = if let Some(l) = it.next() {
-            path().parse(l.as_bytes()).unwrap()
-        } else {
-            vec![]
+        let commands = match it.next() {
+            Some(l) => path().parse(l.as_bytes()).map_err(|e| InputError::new(path_line, &l, e.to_string()))?,
+            None => bail!("the path is missing after the map")
         };
-        println!("walls: {:?}, commands: {:?}", walls.len(), commands.len());
-        World { regions, walls, commands: commands.into_iter().peekable(), fac: R, pos: Coord { row: 0, col: start_col } }
+        debug!("walls: {:?}, commands: {:?}", walls.len(), commands.len());
+        Ok(World { regions, walls, commands: commands.into_iter().peekable(), fac: R, pos: Coord { row: 0, col: start_col } })
     }
 
     fn step(&mut self) -> bool {
         if let Some(cmd) = self.commands.next() {
-            //println!("{:?} {:?} {:?}", cmd, self.fac, self.pos);
+            trace!("{:?} {:?} {:?}", cmd, self.fac, self.pos);
             self.fac = self.fac.advance(cmd);
             if cmd != FWD {
                 return true
@@ -229,45 +242,45 @@ impl World {
 
 }
 
-pub fn solution(it: InputIterator, part_two: bool) -> Ztr {
-    let mut w = World::new(it);
-    (if part_two {
+pub struct Day;
+
+impl Solution for Day {
+    type Input = World;
+    type One = i32;
+    type Two = i32;
+
+    const TITLE: Option<&'static str> = Some("Monkey Map");
+    const IMPLEMENTED: [bool; 2] = [true, false];
+    const EXAMPLES: &'static [Example] = &[Example { input: _TEST_DATA, one: Some("6032"), two: None }];
+
+    fn parse(it: InputIterator) -> Result<Self::Input> {
+        World::new(it)
+    }
+
+    fn part_one(w: &Self::Input) -> Result<Self::One> {
+        let (fac, Coord { row, col}) = w.clone().run();
+        Ok(1000 * (row + 1) + 4 * (col + 1) + fac as i32)
+    }
+
+    fn part_two(_: &Self::Input) -> Result<Self::Two> {
         todo!()
-    } else {
-        let (fac, Coord { row, col}) = w.run();
-        1000 * (row + 1) + 4 * (col + 1) + fac as i32
-    }).to_string().into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    static _TEST_DATA: &str = "        ...#
-        .#..
-        #...
-        ....
-...#.......#
-........#...
-..#....#....
-..........#.
-        ...#....
-        .....#..
-        .#......
-        ......#.
-
-10R5L5R10L4R5L5
-";
-
     #[test]
     fn test1() {
         let r = path().parse(b"4R5L").unwrap();
         assert_eq!(r, vec![FWD, FWD, FWD, FWD, RIGHT, FWD, FWD, FWD, FWD, FWD, LEFT]);
+        assert!(path().parse(b"4R5X").is_err());
     }
 
     #[test]
     fn test2() {
-        let mut w = World::new(& mut (_TEST_DATA.lines().map(|s| s.into())));
+        let mut w = World::new(& mut (_TEST_DATA.lines().map(|s| s.into()))).unwrap();
         assert_eq!(13, w.walls.len());
         assert_eq!(3, w.regions.len());
         assert_eq!(Coord { row: 3, col: 11 }, w.regions[0].lr);
@@ -286,4 +299,11 @@ mod tests {
         assert_eq!(6032, 1000 * (row + 1) + 4 * (col + 1) + dir as i32)
     }
 
+    #[test]
+    fn bad_path() {
+        let data = _TEST_DATA.replace("10R5L", "10R5?");
+        let err = World::new(& mut (data.lines().map(|s| s.to_string().into()))).err().unwrap();
+        assert_eq!(14, err.downcast_ref::<InputError>().unwrap().line);
+    }
+
 }
@@ -1,11 +1,13 @@
 use std::cell::Cell;
 use std::collections::HashMap;
 
+use anyhow::{ensure, Result};
+use log::{debug, trace};
 use num::abs;
 
 use Direction::*;
 
-use crate::{Coord, InputIterator, Ztr};
+use crate::{Coord, Example, InputError, InputIterator, Solution};
 
 static _TEST_DATA: &str = "..............
 ..............
@@ -109,7 +111,7 @@ impl Elf {
 
     fn mv(&self) -> Self {
         if let Some(prop) = self.prop.take() {
-            //println!("moving ({},{}) to ({},{})", self.current.row, self.current.column, prop.row, prop.column);
+            trace!("moving ({},{}) to ({},{})", self.current.row, self.current.col, prop.row, prop.col);
             Elf { current: prop, prop: Cell::new(None) }
         } else {
             Elf { prop: Cell::new(self.prop.get()), .. *self }
@@ -136,25 +138,34 @@ fn step(elves: &[Elf], dirs: &mut dyn Iterator<Item = Direction>) -> (Vec<Elf>,
     (elves.iter().map(Elf::mv).collect::<Vec<_>>(), no_moves)
 }
 
-pub fn solution(it: InputIterator, part_two: bool) -> Ztr {
-    let mut elves: Vec<Elf> = Vec::with_capacity(1000);
-    it.enumerate()
-      .for_each(|(row, line)| line.chars()
-                                              .enumerate()
-                                              .filter(|(_, c)| *c == '#')
-                                              .for_each(|(col, _)| elves.push(Elf::new(row as i32, col as i32))));
-    let mut dirs = vec![N, S, W, E].into_iter().cycle();
-    if part_two {
-        let mut done = false;
-        let mut c = 0;
-        while !done {
-            let r = step(&mut elves, &mut dirs);
-            elves = r.0;
-            done = r.1;
-            c += 1;
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Coord>;
+    type One = i32;
+    type Two = i32;
+
+    const TITLE: Option<&'static str> = Some("Unstable Diffusion");
+    const EXAMPLES: &'static [Example] = &[Example { input: _TEST_DATA, one: Some("110"), two: Some("20") }];
+
+    fn parse(it: InputIterator) -> Result<Self::Input> {
+        let mut elves: Vec<Coord> = Vec::with_capacity(1000);
+        for (row, line) in it.enumerate() {
+            if line.chars().any(|c| c != '#' && c != '.') {
+                return Err(InputError::new(row, &line, "only '#' and '.' are allowed").into())
+            }
+            line.chars()
+                .enumerate()
+                .filter(|(_, c)| *c == '#')
+                .for_each(|(col, _)| elves.push(Coord { row: row as i32, col: col as i32 }));
         }
-        c.to_string().into()
-    } else {
+        ensure!(!elves.is_empty(), "there are no elves in the input");
+        Ok(elves)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::One> {
+        let mut elves = input.iter().map(|c| Elf::new(c.row, c.col)).collect::<Vec<_>>();
+        let mut dirs = vec![N, S, W, E].into_iter().cycle();
         for _ in 0..10 {
             elves = step(&mut elves, &mut dirs).0;
         }
@@ -164,9 +175,23 @@ pub fn solution(it: InputIterator, part_two: bool) -> Ztr {
         let min_row = elves.iter().map(|e| e.current.row).min().unwrap();
         let w = max_col - min_col + 1;
         let h = max_row - min_row + 1;
-        //println!("{} {} {} {}", min_row, max_row, min_col, max_col);
-        println!("h {} w {}", h, w);
-        (h * w - elves.len() as i32).to_string().into()
+        debug!("rows {}..={} cols {}..={}", min_row, max_row, min_col, max_col);
+        debug!("h {} w {}", h, w);
+        Ok(h * w - elves.len() as i32)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::Two> {
+        let mut elves = input.iter().map(|c| Elf::new(c.row, c.col)).collect::<Vec<_>>();
+        let mut dirs = vec![N, S, W, E].into_iter().cycle();
+        let mut done = false;
+        let mut c = 0;
+        while !done {
+            let r = step(&mut elves, &mut dirs);
+            elves = r.0;
+            done = r.1;
+            c += 1;
+        }
+        Ok(c)
     }
 }
 
@@ -175,12 +200,12 @@ mod test {
 
     #[test]
     fn test1() {
-        assert_eq!("110", solution(&mut (_TEST_DATA.lines().map(|s| s.into())), false));
+        assert_eq!(110, Day::part_one(&Day::parse(&mut (_TEST_DATA.lines().map(|s| s.into()))).unwrap()).unwrap());
     }
 
     #[test]
     fn test2() {
-        assert_eq!("20", solution(&mut (_TEST_DATA.lines().map(|s| s.into())), true));
+        assert_eq!(20, Day::part_two(&Day::parse(&mut (_TEST_DATA.lines().map(|s| s.into()))).unwrap()).unwrap());
     }
 
 }
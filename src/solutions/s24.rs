@@ -1,9 +1,18 @@
 use std::collections::HashSet;
 use std::collections::vec_deque::VecDeque;
 
+use anyhow::{bail, Result};
 use itertools::Itertools;
 
-use crate::{Coord, InputIterator, Ztr};
+use crate::{Coord, Example, InputError, InputIterator, Solution};
+
+static _TEST_DATA: &str = "#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#
+";
 
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 struct Blizzard {
@@ -81,7 +90,8 @@ impl Pos {
     }
 }
 
-struct World {
+#[derive(Clone)]
+pub struct World {
     w: i32,
     h: i32,
     blizzards: Vec<Blizzard>,
@@ -91,11 +101,14 @@ struct World {
 
 impl World {
 
-    fn new(it: InputIterator) -> Self {
+    fn new(it: InputIterator) -> Result<Self, InputError> {
         let mut w: i32 = 0;
         let mut h = 0;
         let mut blizzards: Vec<Blizzard> = Vec::with_capacity(1000);
         for (row, line) in it.enumerate() {
+            if let Some(c) = line.chars().find(|&c| !"#.<>^v".contains(c)) {
+                return Err(InputError::new(row, &line, format!("unexpected {:?} in the valley", c)))
+            }
             h = row as i32;
             for (col, content) in line.chars().enumerate() {
                 if w < col as i32 {
@@ -107,10 +120,10 @@ impl World {
             }
         }
         let positions: VecDeque<Pos> = VecDeque::with_capacity(8);
-        World { w, h, blizzards, positions, occupied: HashSet::with_capacity(1000) }
+        Ok(World { w, h, blizzards, positions, occupied: HashSet::with_capacity(1000) })
     }
 
-    fn step(& mut self, to: &Coord) -> Option<i32> {
+    fn step(& mut self, to: &Coord) -> Result<Option<i32>> {
         self.occupied.clear();
         self.blizzards = self.blizzards.iter()
             .map(|b| b.advance(self.w, self.h))
@@ -122,70 +135,80 @@ impl World {
             .unique()
             .collect();
         if self.positions.is_empty() {
-            panic!("STUCK");
+            bail!("stuck: the blizzards cover every position on the way to {:?}", to);
         }
         if let Some(Pos { step, .. }) = self.positions.iter().filter(|p| p.coord == *to).next() {
-            return Some(*step);
+            return Ok(Some(*step));
         }
-        None
+        Ok(None)
     }
 
-    fn run(&mut self, from: &Coord, to: &Coord) -> i32 {
+    fn run(&mut self, from: &Coord, to: &Coord) -> Result<i32> {
         self.positions.clear();
         self.positions.push_back(Pos::new(from.row, from.col));
         loop {
-            if let Some(n) = self.step(to) {
-                break n
+            if let Some(n) = self.step(to)? {
+                break Ok(n)
             }
         }
     }
 
 }
 
-pub fn solution(it: InputIterator, part_two: bool) -> Ztr {
-    let mut world = World::new(it);
-    let start = Coord { row: 0, col: 1 };
-    let finish = Coord { row: world.h, col: world.w - 1 };
-    (if part_two {
-        let mut n = world.run(&start, &finish);
-        n += world.run(&finish, &start);
-        n += world.run(&start, &finish);
-        n
-    } else {
+pub struct Day;
+
+impl Solution for Day {
+    type Input = World;
+    type One = i32;
+    type Two = i32;
+
+    const TITLE: Option<&'static str> = Some("Blizzard Basin");
+    const EXAMPLES: &'static [Example] = &[Example { input: _TEST_DATA, one: Some("18"), two: Some("54") }];
+
+    fn parse(it: InputIterator) -> Result<Self::Input> {
+        Ok(World::new(it)?)
+    }
+
+    fn part_one(world: &Self::Input) -> Result<Self::One> {
+        let mut world = world.clone();
+        let start = Coord { row: 0, col: 1 };
+        let finish = Coord { row: world.h, col: world.w - 1 };
         world.run(&start, &finish)
-    }).to_string().into()
+    }
+
+    fn part_two(world: &Self::Input) -> Result<Self::Two> {
+        let mut world = world.clone();
+        let start = Coord { row: 0, col: 1 };
+        let finish = Coord { row: world.h, col: world.w - 1 };
+        let mut n = world.run(&start, &finish)?;
+        n += world.run(&finish, &start)?;
+        n += world.run(&start, &finish)?;
+        Ok(n)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    static _TEST_DATA: &str = "#.######
-#>>.<^<#
-#.<..<<#
-#>v.><>#
-#<^v^^>#
-######.#
-";
-
     #[test]
     fn test1() {
-        let mut world = World::new(& mut _TEST_DATA.lines().map(|l| l.into()));
+        let mut world = World::new(& mut _TEST_DATA.lines().map(|l| l.into())).unwrap();
         let start = Coord { row: 0, col: 1 };
         let finish = Coord { row: world.h, col: world.w - 1 };
         world.positions.push_back(Pos::new(start.row, start.col));
-        //println!("{:?}\n{:?}\n----------", &world.blizzards, &world.positions);
-        world.step(&finish);
-        world.step(&finish);
-        world.step(&finish);
-        world.step(&finish);
+        log::debug!("{:?}\n{:?}\n----------", &world.blizzards, &world.positions);
+        world.step(&finish).unwrap();
+        world.step(&finish).unwrap();
+        world.step(&finish).unwrap();
+        world.step(&finish).unwrap();
         assert_eq!(2, world.blizzards.iter().filter(|b| b.coord == Coord { row: 1, col: 5 }).count());
         assert!(world.blizzards.contains(&Blizzard { dir: '<', coord: Coord { row: 3, col: 1 } }));
         assert!(world.blizzards.contains(&Blizzard{ dir: '^', coord: Coord { row: 4, col: 2 }}));
         assert!(world.blizzards.contains(&Blizzard{ dir: '<', coord: Coord { row: 1, col: 2 }}));
         //assert!(world.positions.contains(&Pos{ step: 4, coord: Coord { row: 1, col: 1 }}));
         let n = loop {
-            if let Some(n) = world.step(&finish) {
+            if let Some(n) = world.step(&finish).unwrap() {
                break n
             }
         };
@@ -194,14 +217,14 @@ mod tests {
 
     #[test]
     fn test2() {
-        let mut world = World::new(& mut _TEST_DATA.lines().map(|l| l.into()));
+        let mut world = World::new(& mut _TEST_DATA.lines().map(|l| l.into())).unwrap();
         let start = Coord { row: 0, col: 1 };
         let goal = Coord { row: world.h, col: world.w - 1 };
-        let mut n = world.run(&start, &goal);
+        let mut n = world.run(&start, &goal).unwrap();
         assert_eq!(18, n);
-        n = world.run(&goal, &start);
+        n = world.run(&goal, &start).unwrap();
         assert_eq!(23, n);
-        n = world.run(&start, &goal);
+        n = world.run(&start, &goal).unwrap();
         assert_eq!(13, n);
     }
 
@@ -0,0 +1,234 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::iter::Sum;
+use std::ops::Add;
+use std::str::FromStr;
+
+use anyhow::Result;
+use num::{BigInt, Zero};
+
+use crate::{Answer, Example, InputError, InputIterator, Solution};
+
+static _TEST_DATA: &str = "1=-0-2
+12111
+2=0=
+21
+2=01
+111
+20012
+112
+1=-1=
+1-12
+12
+1=
+122
+";
+
+/// Day 25 has no second puzzle, its star comes with the other 49.
+const LAST_STAR: &str = "Merry Christmas";
+
+/// Why a [Snafu] could not be read or doesn't fit an integer type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SnafuError {
+    Empty,
+    Digit(char),
+    Overflow,
+}
+
+impl Display for SnafuError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SnafuError::Empty => write!(f, "no digits"),
+            SnafuError::Digit(c) => write!(f, "{:?} is not a SNAFU digit", c),
+            SnafuError::Overflow => write!(f, "number too large"),
+        }
+    }
+}
+
+impl Error for SnafuError {}
+
+/// A number in balanced base five, written with the digits `=` (-2), `-` (-1), `0`, `1` and `2`.
+/// It has as many digits as it needs, only the conversions to machine integers can overflow.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct Snafu {
+    /// least significant first and without leading zeros, zero has none at all
+    digits: Vec<i8>,
+}
+
+impl Snafu {
+    fn new(mut digits: Vec<i8>) -> Self {
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        Snafu { digits }
+    }
+}
+
+impl FromStr for Snafu {
+    type Err = SnafuError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(SnafuError::Empty)
+        }
+        s.chars().rev().map(|c| match c {
+            '=' => Ok(-2),
+            '-' => Ok(-1),
+            '0' => Ok(0),
+            '1' => Ok(1),
+            '2' => Ok(2),
+            c => Err(SnafuError::Digit(c)),
+        }).collect::<Result<_, _>>().map(Snafu::new)
+    }
+}
+
+impl Display for Snafu {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.digits.is_empty() {
+            return f.pad("0")
+        }
+        let s = self.digits.iter().rev().map(|d| match d {
+            -2 => '=',
+            -1 => '-',
+            d => (b'0' + *d as u8) as char,
+        }).collect::<String>();
+        f.pad(&s)
+    }
+}
+
+impl From<i128> for Snafu {
+    fn from(mut n: i128) -> Self {
+        let mut digits = vec![];
+        while n != 0 {
+            let (mut quotient, digit) = (n.div_euclid(5), n.rem_euclid(5) as i8);
+            if digit > 2 {
+                quotient += 1;
+            }
+            digits.push(if digit > 2 { digit - 5 } else { digit });
+            n = quotient;
+        }
+        Snafu { digits }
+    }
+}
+
+impl From<i64> for Snafu {
+    fn from(n: i64) -> Self {
+        Snafu::from(n as i128)
+    }
+}
+
+impl From<&Snafu> for BigInt {
+    fn from(snafu: &Snafu) -> Self {
+        snafu.digits.iter().rev().fold(BigInt::zero(), |n, &d| n * 5 + d)
+    }
+}
+
+impl TryFrom<&Snafu> for i128 {
+    type Error = SnafuError;
+
+    fn try_from(snafu: &Snafu) -> Result<Self, Self::Error> {
+        i128::try_from(BigInt::from(snafu)).map_err(|_| SnafuError::Overflow)
+    }
+}
+
+impl TryFrom<&Snafu> for i64 {
+    type Error = SnafuError;
+
+    fn try_from(snafu: &Snafu) -> Result<Self, Self::Error> {
+        i64::try_from(BigInt::from(snafu)).map_err(|_| SnafuError::Overflow)
+    }
+}
+
+impl Add for &Snafu {
+    type Output = Snafu;
+
+    /// Digit by digit, carrying into as many more digits as the sum takes.
+    fn add(self, other: &Snafu) -> Snafu {
+        let width = self.digits.len().max(other.digits.len());
+        let mut digits = Vec::with_capacity(width + 1);
+        let mut carry = 0;
+        for n in 0..width {
+            let sum = self.digits.get(n).unwrap_or(&0) + other.digits.get(n).unwrap_or(&0) + carry;
+            let digit;
+            (carry, digit) = match sum {
+                ..=-3 => (-1, sum + 5),
+                3.. => (1, sum - 5),
+                _ => (0, sum),
+            };
+            digits.push(digit);
+        }
+        digits.push(carry);
+        Snafu::new(digits)
+    }
+}
+
+impl Add for Snafu {
+    type Output = Snafu;
+
+    fn add(self, other: Snafu) -> Snafu {
+        &self + &other
+    }
+}
+
+impl<'a> Sum<&'a Snafu> for Snafu {
+    fn sum<I: Iterator<Item = &'a Snafu>>(iter: I) -> Self {
+        iter.fold(Snafu::default(), |acc, n| &acc + n)
+    }
+}
+
+impl Sum for Snafu {
+    fn sum<I: Iterator<Item = Snafu>>(iter: I) -> Self {
+        iter.fold(Snafu::default(), |acc, n| &acc + &n)
+    }
+}
+
+impl From<Snafu> for Answer {
+    fn from(n: Snafu) -> Self {
+        Answer::Text(n.to_string())
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Snafu>;
+    type One = Snafu;
+    type Two = &'static str;
+
+    const TITLE: Option<&'static str> = Some("Full of Hot Air");
+    const EXAMPLES: &'static [Example] = &[Example { input: _TEST_DATA, one: Some("2=-1=0"), two: Some(LAST_STAR) }];
+
+    fn parse(it: InputIterator) -> Result<Self::Input> {
+        Ok(it.enumerate()
+            .map(|(n, line)| line.parse().map_err(|e: SnafuError| InputError::new(n, &line, e.to_string())))
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part_one(numbers: &Self::Input) -> Result<Self::One> {
+        Ok(numbers.iter().sum())
+    }
+
+    fn part_two(_: &Self::Input) -> Result<Self::Two> {
+        Ok(LAST_STAR)
+    }
+}
+
+#[test]
+fn conversions() {
+    for (n, s) in [(0, "0"), (3, "1="), (2022, "1=11-2"), (-2022, "-2--1="), (314159265, "1121-1110-1=0")] {
+        assert_eq!(s, Snafu::from(n).to_string());
+        assert_eq!(Ok(n), i64::try_from(&s.parse::<Snafu>().unwrap()));
+    }
+    for n in [i128::MIN, i128::MAX] {
+        assert_eq!(Ok(n), i128::try_from(&Snafu::from(n)));
+    }
+    assert_eq!(Err(SnafuError::Overflow), i64::try_from(&Snafu::from(i64::MAX as i128 + 1)));
+    assert_eq!(Err(SnafuError::Digit('3')), "1=3".parse::<Snafu>());
+}
+
+#[test]
+fn carries() {
+    let sum = ["2", "2", "2", "2", "2", "2", "2"].iter().map(|s| s.parse::<Snafu>().unwrap()).sum::<Snafu>();
+    assert_eq!("1=-", sum.to_string());
+    assert_eq!(Snafu::default(), Snafu::from(7_i64) + Snafu::from(-7_i64));
+}